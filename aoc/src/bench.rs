@@ -0,0 +1,85 @@
+use std::{io::Write, time::Duration};
+
+use clap::ValueEnum;
+use common::bench::Timings;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+/// One day's timings, in microseconds so they can be compared across runs.
+#[derive(Debug, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub parse_us: f64,
+    pub part1_us: f64,
+    pub part2_us: f64,
+    pub total_us: f64,
+}
+
+impl Row {
+    pub fn new(day: u8, timings: &Timings) -> Self {
+        Self {
+            day,
+            parse_us: micros(timings.parse),
+            part1_us: micros(timings.part1),
+            part2_us: micros(timings.part2),
+            total_us: micros(timings.total()),
+        }
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+pub fn write_report(
+    rows: &[(u8, Timings)],
+    format: Format,
+    mut out: impl Write,
+) -> Result<(), anyhow::Error> {
+    match format {
+        Format::Table => write_table(rows, out)?,
+        Format::Json => {
+            let rows: Vec<Row> = rows.iter().map(|(day, t)| Row::new(*day, t)).collect();
+            serde_json::to_writer_pretty(&mut out, &rows)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for (day, timings) in rows {
+                writer.serialize(Row::new(*day, timings))?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_table(rows: &[(u8, Timings)], mut out: impl Write) -> Result<(), std::io::Error> {
+    writeln!(
+        out,
+        "{:<5}{:>12}{:>12}{:>12}{:>12}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    )?;
+
+    for (day, timings) in rows {
+        writeln!(
+            out,
+            "{:<5}{:>12}{:>12}{:>12}{:>12}",
+            format!("{day:02}"),
+            format!("{:.2?}", timings.parse),
+            format!("{:.2?}", timings.part1),
+            format!("{:.2?}", timings.part2),
+            format!("{:.2?}", timings.total()),
+        )?;
+    }
+
+    let total: Duration = rows.iter().map(|(_, timings)| timings.total()).sum();
+    writeln!(out, "{:<5}{:>48}", "All", format!("{total:.2?}"))
+}
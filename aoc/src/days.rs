@@ -0,0 +1,110 @@
+use anyhow::anyhow;
+use common::{bench::Timings, params, Solution};
+
+pub const ALL_DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Calls a function that's generic over `Solution` with the given day's solution.
+macro_rules! with_day {
+    ($day:expr, $($func:ident)::+, $($arg:expr),*) => {
+        match $day {
+            1 => $($func)::+::<day_01::Day01>($($arg),*),
+            2 => $($func)::+::<day_02::Day02>($($arg),*),
+            3 => $($func)::+::<day_03::Day03>($($arg),*),
+            4 => $($func)::+::<day_04::Day04>($($arg),*),
+            5 => $($func)::+::<day_05::Day05>($($arg),*),
+            6 => $($func)::+::<day_06::Day06>($($arg),*),
+            7 => $($func)::+::<day_07::Day07>($($arg),*),
+            8 => $($func)::+::<day_08::Day08>($($arg),*),
+            9 => $($func)::+::<day_09::Day09>($($arg),*),
+            10 => $($func)::+::<day_10::Day10>($($arg),*),
+            11 => $($func)::+::<day_11::Day11>($($arg),*),
+            12 => $($func)::+::<day_12::Day12>($($arg),*),
+            13 => $($func)::+::<day_13::Day13>($($arg),*),
+            14 => $($func)::+::<day_14::Day14>($($arg),*),
+            15 => $($func)::+::<day_15::Day15>($($arg),*),
+            16 => $($func)::+::<day_16::Day16>($($arg),*),
+            17 => $($func)::+::<day_17::Day17>($($arg),*),
+            18 => $($func)::+::<day_18::Day18>($($arg),*),
+            19 => $($func)::+::<day_19::Day19>($($arg),*),
+            20 => $($func)::+::<day_20::Day20>($($arg),*),
+            21 => $($func)::+::<day_21::Day21>($($arg),*),
+            22 => $($func)::+::<day_22::Day22>($($arg),*),
+            23 => $($func)::+::<day_23::Day23>($($arg),*),
+            24 => $($func)::+::<day_24::Day24>($($arg),*),
+            25 => $($func)::+::<day_25::Day25>($($arg),*),
+            day => Err(anyhow!("no solution for day {day}")),
+        }
+    };
+}
+
+/// Parses the day's input once and returns the answers to each of the requested parts. `params`
+/// gets turned into the day's own parameters, with anything missing left at its default.
+pub fn solve(
+    day: u8,
+    input: &str,
+    params: &toml::Table,
+    parts: &[u8],
+) -> Result<Vec<String>, anyhow::Error> {
+    with_day!(day, solve_with_params, input, params, parts)
+}
+
+pub fn bench(
+    day: u8,
+    input: &str,
+    params: &toml::Table,
+    warmup: u32,
+    runs: u32,
+) -> Result<Timings, anyhow::Error> {
+    with_day!(day, bench_with_params, input, params, warmup, runs)
+}
+
+/// Draws a picture of the day's answer, for the days that have one.
+pub fn render(
+    day: u8,
+    input: &str,
+    params: &toml::Table,
+    color: bool,
+) -> Result<String, anyhow::Error> {
+    match day {
+        14 => {
+            let hq = parse_with_params::<day_14::Day14>(input, params)?;
+            Ok(day_14::render_christmas_tree(&hq, color))
+        }
+        16 => {
+            let map = parse_with_params::<day_16::Day16>(input, params)?;
+            Ok(map.render_best_paths(color))
+        }
+        20 => {
+            let map = parse_with_params::<day_20::Day20>(input, params)?;
+            Ok(map.input.render_best_cheat(20, color))
+        }
+        day => Err(anyhow!("day {day} doesn't have anything to draw")),
+    }
+}
+
+fn parse_with_params<S: Solution>(
+    input: &str,
+    params: &toml::Table,
+) -> Result<S::Input, anyhow::Error> {
+    let params = params::from_table::<S::Params>(params.clone())?;
+    S::parse(input, &params)
+}
+
+fn solve_with_params<S: Solution>(
+    input: &str,
+    params: &toml::Table,
+    parts: &[u8],
+) -> Result<Vec<String>, anyhow::Error> {
+    let params = params::from_table::<S::Params>(params.clone())?;
+    common::solve::<S>(input, &params, parts)
+}
+
+fn bench_with_params<S: Solution>(
+    input: &str,
+    params: &toml::Table,
+    warmup: u32,
+    runs: u32,
+) -> Result<Timings, anyhow::Error> {
+    let params = params::from_table::<S::Params>(params.clone())?;
+    common::bench::bench::<S>(input, &params, warmup, runs)
+}
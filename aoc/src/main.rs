@@ -0,0 +1,315 @@
+use std::{
+    fs::File,
+    io::{stdout, IsTerminal},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context};
+use clap::{Parser, Subcommand};
+use common::inputs::{InputError, InputSource, Inputs};
+
+mod bench;
+mod days;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Directory to look for inputs in before the repo, as day-NN/<name>.txt. Defaults to
+    /// $AOC_DATA_DIR
+    #[arg(long, global = true)]
+    data_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or all days and print their answers
+    Run {
+        /// Day to run, runs every day if not given
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Part to run, runs both parts if not given
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input to use, either a name like `example`, a path to a file, or `-` for stdin
+        #[arg(short, long, default_value = "real")]
+        input: InputSource,
+
+        /// Override a puzzle parameter, like `--param grid_size=7`. Takes priority over the
+        /// parameters in the .toml file next to the input
+        #[arg(
+            short = 'P',
+            long = "param",
+            value_name = "NAME=VALUE",
+            requires = "day"
+        )]
+        params: Vec<String>,
+    },
+    /// Time parsing and both parts of one or all days
+    Bench {
+        /// Day to time, times every day if not given
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Input to use, either a name like `example`, a path to a file, or `-` for stdin
+        #[arg(short, long, default_value = "real")]
+        input: InputSource,
+
+        /// Override a puzzle parameter, like `--param grid_size=7`. Takes priority over the
+        /// parameters in the .toml file next to the input
+        #[arg(
+            short = 'P',
+            long = "param",
+            value_name = "NAME=VALUE",
+            requires = "day"
+        )]
+        params: Vec<String>,
+
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+
+        /// Timed runs to average over
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Format to report the timings in
+        #[arg(short, long, value_enum, default_value_t = bench::Format::Table)]
+        format: bench::Format,
+
+        /// File to write the report to, prints it if not given
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Draw a picture of a day's answer: day 14's christmas tree, day 16's best paths or day
+    /// 20's best cheat
+    Render {
+        /// Day to draw
+        #[arg(short, long)]
+        day: u8,
+
+        /// Input to use, either a name like `example`, a path to a file, or `-` for stdin
+        #[arg(short, long, default_value = "real")]
+        input: InputSource,
+
+        /// Override a puzzle parameter, like `--param grid_size=7`. Takes priority over the
+        /// parameters in the .toml file next to the input
+        #[arg(short = 'P', long = "param", value_name = "NAME=VALUE")]
+        params: Vec<String>,
+    },
+    /// Check every day's answers against the ones recorded in the answers file
+    Verify {
+        /// Day to check, checks every day if not given
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Answers file, defaults to answers.toml at the root of the repo
+        #[arg(short, long)]
+        answers: Option<PathBuf>,
+
+        /// Save the current answer for any day and part that doesn't have one recorded yet
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let cli = Cli::parse();
+    let inputs = match cli.data_dir {
+        Some(dir) => Inputs::new(Some(dir)),
+        None => Inputs::from_env(),
+    };
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+        } => run(&inputs, day, part, &input, &params),
+        Command::Bench {
+            day,
+            input,
+            params,
+            warmup,
+            runs,
+            format,
+            output,
+        } => bench(&inputs, day, &input, &params, warmup, runs, format, output),
+        Command::Render { day, input, params } => render(&inputs, day, &input, &params),
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => verify(&inputs, day, answers, record),
+    }
+}
+
+fn run(
+    inputs: &Inputs,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: &InputSource,
+    overrides: &[String],
+) -> Result<(), anyhow::Error> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    for day in select_days(day, input)? {
+        let input = inputs.read(day, input)?;
+        let params = load_params(input.path.as_deref(), overrides)?;
+
+        let answers = days::solve(day, &input.contents, &params, &parts)
+            .with_context(|| format!("failed to solve day {day} with {input}"))?;
+
+        for (part, answer) in parts.iter().zip(answers) {
+            println!("Day {day:02}, Part {part}: {answer}");
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bench(
+    inputs: &Inputs,
+    day: Option<u8>,
+    input: &InputSource,
+    overrides: &[String],
+    warmup: u32,
+    runs: u32,
+    format: bench::Format,
+    output: Option<PathBuf>,
+) -> Result<(), anyhow::Error> {
+    let rows = select_days(day, input)?
+        .into_iter()
+        .map(|day| {
+            let input = inputs.read(day, input)?;
+            let params = load_params(input.path.as_deref(), overrides)?;
+            let timings = days::bench(day, &input.contents, &params, warmup, runs)
+                .with_context(|| format!("failed to bench day {day} with {input}"))?;
+            Ok((day, timings))
+        })
+        .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+    match output {
+        Some(path) => {
+            let file = File::create(&path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            bench::write_report(&rows, format, file)
+        }
+        None => bench::write_report(&rows, format, stdout().lock()),
+    }
+}
+
+fn render(
+    inputs: &Inputs,
+    day: u8,
+    input: &InputSource,
+    overrides: &[String],
+) -> Result<(), anyhow::Error> {
+    let input = inputs.read(day, input)?;
+    let params = load_params(input.path.as_deref(), overrides)?;
+    let picture = days::render(day, &input.contents, &params, stdout().is_terminal())
+        .with_context(|| format!("failed to draw day {day} with {input}"))?;
+    println!("{picture}");
+    Ok(())
+}
+
+fn verify(
+    inputs: &Inputs,
+    day: Option<u8>,
+    answers: Option<PathBuf>,
+    record: bool,
+) -> Result<(), anyhow::Error> {
+    let answers_path = answers.unwrap_or_else(verify::default_answers_path);
+    let mut answers = verify::Answers::load(&answers_path)?;
+
+    let (mut passed, mut failed, mut missing, mut skipped) = (0, 0, 0, 0);
+    for day in select_days(day, &InputSource::default())? {
+        let parts = [1, 2];
+        let results = match inputs.read(day, &InputSource::default()) {
+            Ok(input) => load_params(input.path.as_deref(), &[])
+                .and_then(|params| days::solve(day, &input.contents, &params, &parts))
+                .map_err(|e| verify::Outcome::Error(format!("{e:#}"))),
+            Err(InputError::NotFound { .. }) => Err(verify::Outcome::NoInput),
+            Err(e) => Err(verify::Outcome::Error(format!(
+                "{:#}",
+                anyhow::Error::from(e)
+            ))),
+        };
+
+        for (i, part) in parts.into_iter().enumerate() {
+            let outcome = match &results {
+                Ok(results) => verify::Outcome::check(answers.get(day, part), results[i].clone()),
+                Err(outcome) => outcome.clone(),
+            };
+            println!("Day {day:02}, Part {part}: {outcome}");
+
+            match outcome {
+                verify::Outcome::Pass => passed += 1,
+                verify::Outcome::Missing { actual } => {
+                    missing += 1;
+                    if record {
+                        answers.set(day, part, actual);
+                    }
+                }
+                verify::Outcome::NoInput => skipped += 1,
+                verify::Outcome::Fail { .. } | verify::Outcome::Error(_) => failed += 1,
+            }
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {missing} missing, {skipped} skipped");
+
+    if record && missing > 0 {
+        answers.save(&answers_path)?;
+        println!("recorded {missing} answers in {}", answers_path.display());
+    } else if missing > 0 {
+        println!(
+            "run with --record to save the missing answers in {}",
+            answers_path.display()
+        );
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("{failed} answers failed verification"));
+    }
+
+    Ok(())
+}
+
+fn select_days(day: Option<u8>, input: &InputSource) -> Result<Vec<u8>, anyhow::Error> {
+    if day.is_none() && *input == InputSource::Stdin {
+        return Err(anyhow!("reading the input from stdin needs a --day"));
+    }
+
+    match day {
+        Some(day) if days::ALL_DAYS.contains(&day) => Ok(vec![day]),
+        Some(day) => Err(anyhow!("no solution for day {day}")),
+        None => Ok(days::ALL_DAYS.collect()),
+    }
+}
+
+/// The parameters for an input, from the .toml file next to it with `overrides` on top. Input
+/// from stdin has nowhere to keep a .toml file, so only gets the overrides.
+fn load_params(
+    input_path: Option<&Path>,
+    overrides: &[String],
+) -> Result<toml::Table, anyhow::Error> {
+    let mut params = match input_path {
+        Some(path) => common::params::load(path)?,
+        None => toml::Table::new(),
+    };
+    for arg in overrides {
+        common::params::set_override(&mut params, arg)?;
+    }
+    Ok(params)
+}
@@ -0,0 +1,109 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::{read_to_string, write},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+/// Known-correct answers for the real inputs, keyed by day like `[day-01]`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Answers {
+    /// Loads the answers file, treating a file that doesn't exist yet as empty.
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        match read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        write(path, toml::to_string(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&key(day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        let answers = self.0.entry(key(day)).or_default();
+        match part {
+            1 => answers.part1 = Some(answer),
+            2 => answers.part2 = Some(answer),
+            _ => {}
+        }
+    }
+}
+
+pub fn default_answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml")
+}
+
+fn key(day: u8) -> String {
+    format!("day-{day:02}")
+}
+
+#[derive(Clone)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There's no recorded answer to check against.
+    Missing {
+        actual: String,
+    },
+    /// There's no input to run the day on, which is normal for a checkout without the real inputs.
+    NoInput,
+    /// The day couldn't be run at all, e.g. because its input couldn't be read or didn't parse.
+    Error(String),
+}
+
+impl Outcome {
+    pub fn check(expected: Option<&str>, actual: String) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+            None => Outcome::Missing { actual },
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Outcome::Missing { actual } => write!(f, "missing (got {actual})"),
+            Outcome::NoInput => write!(f, "skipped (no input)"),
+            Outcome::Error(e) => write!(f, "ERROR ({e})"),
+        }
+    }
+}
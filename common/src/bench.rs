@@ -0,0 +1,57 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::Solution;
+
+/// Average time spent in each step of a day's solution.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Times parsing and both parts separately. Everything gets run `warmup` times untimed first,
+/// then the timings are averaged over `runs` runs.
+pub fn bench<S: Solution>(
+    input: &str,
+    params: &S::Params,
+    warmup: u32,
+    runs: u32,
+) -> Result<Timings, anyhow::Error> {
+    for _ in 0..warmup {
+        let parsed = S::parse(black_box(input), params)?;
+        black_box(S::part1(&parsed));
+        black_box(S::part2(&parsed));
+    }
+
+    let mut totals = Timings::default();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input), params)?;
+        totals.parse += start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        totals.part1 += start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        totals.part2 += start.elapsed();
+    }
+
+    let runs = runs.max(1);
+    Ok(Timings {
+        parse: totals.parse / runs,
+        part1: totals.part1 / runs,
+        part2: totals.part2 / runs,
+    })
+}
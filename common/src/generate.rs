@@ -0,0 +1,295 @@
+//! Random but valid puzzle inputs, for property tests that check the solutions against slower but
+//! obviously right ones. Everything is seeded, so a failing case can be run again with
+//! `AOC_SEED=<seed>`.
+
+use std::{
+    env,
+    fmt::Write,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Environment variable to rerun a property with just the one seed.
+pub const SEED_VAR: &str = "AOC_SEED";
+
+/// Seed the cases are made from when `AOC_SEED` isn't set, so the tests are the same every run.
+const BASE_SEED: u64 = 20241225;
+
+/// A small splitmix64 generator. Nowhere near good enough for anything but making test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "can't pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in the range, ends included.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Runs `property` against `cases` differently seeded generators, or just the one from
+/// `AOC_SEED` if it's set. A failing case panics with the seed that caused it.
+pub fn check(cases: u32, mut property: impl FnMut(&mut Rng)) {
+    let seeds: Vec<u64> = match env::var(SEED_VAR) {
+        Ok(seed) => vec![seed
+            .parse()
+            .unwrap_or_else(|_| panic!("{SEED_VAR} should be a number, got {seed:?}"))],
+        Err(_) => {
+            let mut seeds = Rng::new(BASE_SEED);
+            (0..cases).map(|_| seeds.next_u64()).collect()
+        }
+    };
+
+    for seed in seeds {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed))));
+        if result.is_err() {
+            panic!("property failed with seed {seed}, rerun it with {SEED_VAR}={seed}");
+        }
+    }
+}
+
+/// A grid of characters picked from `cells`, one row per line.
+pub fn grid(rng: &mut Rng, width: usize, height: usize, cells: &[char]) -> String {
+    (0..height)
+        .map(|_| (0..width).map(|_| *rng.choose(cells)).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A garden for day 12. Only a few kinds of plant, so regions get big and wrap around each other.
+pub fn garden_map(rng: &mut Rng) -> String {
+    let plants = &['A', 'B', 'C', 'D'][..rng.range(1..=4) as usize];
+    let (width, height) = (rng.range(1..=12) as usize, rng.range(1..=12) as usize);
+    grid(rng, width, height, plants)
+}
+
+/// Calibration equations for day 7. About half of them are made to come out right with some mix
+/// of `+`, `*` and `||`, the rest have a made up result that almost never does.
+pub fn equations(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..rng.range(1..=10) {
+        let nums: Vec<u64> = (0..rng.range(1..=6)).map(|_| rng.range(1..=99)).collect();
+
+        let result = if rng.chance(0.5) {
+            nums[1..]
+                .iter()
+                .fold(nums[0], |acc, &n| match rng.below(3) {
+                    0 => acc + n,
+                    1 => acc * n,
+                    _ => format!("{acc}{n}").parse().unwrap(),
+                })
+        } else {
+            rng.range(1..=999_999)
+        };
+
+        let nums: Vec<_> = nums.iter().map(u64::to_string).collect();
+        writeln!(input, "{result}: {}", nums.join(" ")).unwrap();
+    }
+    input
+}
+
+/// A dense disk map for day 9, with files of 1 to 9 blocks and gaps of 0 to 9.
+pub fn disk_map(rng: &mut Rng) -> String {
+    let files = rng.range(1..=20);
+    let mut map = String::new();
+    for i in 0..files {
+        map.push(char::from_digit(rng.range(1..=9) as u32, 10).unwrap());
+        if i + 1 < files {
+            map.push(char::from_digit(rng.range(0..=9) as u32, 10).unwrap());
+        }
+    }
+    map
+}
+
+/// Claw machines for day 13. About a quarter of them have buttons that move the claw in the
+/// same direction, some have buttons that don't move it along an axis at all, and about half have
+/// a prize that's reachable in roughly 100 presses of each.
+pub fn claw_machines(rng: &mut Rng) -> String {
+    let machines: Vec<_> = (0..rng.range(1..=8))
+        .map(|_| {
+            let (a, b) = if rng.chance(0.25) {
+                let (dx, dy) = (translation(rng, 9), translation(rng, 9));
+                let (a_steps, b_steps) = (rng.range(1..=9), rng.range(1..=9));
+                ((dx * a_steps, dy * a_steps), (dx * b_steps, dy * b_steps))
+            } else {
+                (
+                    (translation(rng, 99), translation(rng, 99)),
+                    (translation(rng, 99), translation(rng, 99)),
+                )
+            };
+            let prize = prize(rng, a, b);
+            machine(a, b, prize)
+        })
+        .collect();
+    machines.join("\n\n")
+}
+
+/// How far a button moves the claw along one axis, which is now and then not at all.
+fn translation(rng: &mut Rng, max: u64) -> u64 {
+    if rng.chance(0.1) {
+        0
+    } else {
+        rng.range(1..=max)
+    }
+}
+
+fn prize(rng: &mut Rng, a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    if rng.chance(0.5) {
+        let (a_presses, b_presses) = (rng.range(0..=110), rng.range(0..=110));
+        (
+            a.0 * a_presses + b.0 * b_presses,
+            a.1 * a_presses + b.1 * b_presses,
+        )
+    } else {
+        (rng.range(0..=20_000), rng.range(0..=20_000))
+    }
+}
+
+fn machine(a: (u64, u64), b: (u64, u64), prize: (u64, u64)) -> String {
+    format!(
+        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+        a.0, a.1, b.0, b.1, prize.0, prize.1
+    )
+}
+
+/// A program for day 17 shaped like the real ones, which go through `a` three bits at a time and
+/// output one number for each, with a random `a` to start with.
+pub fn three_bit_program(rng: &mut Rng) -> String {
+    let mut body = vec![[1, rng.below(8)], [4, rng.below(8)], [0, 3]];
+    rng.shuffle(&mut body);
+    body.push([5, 5]);
+    if rng.chance(0.5) {
+        // shifting `a` can happen after the output too
+        let adv = body.iter().position(|ins| *ins == [0, 3]).unwrap();
+        body.remove(adv);
+        body.push([0, 3]);
+    }
+
+    let program: Vec<_> = [[2, 4], [1, rng.below(8)], [7, 5]]
+        .into_iter()
+        .chain(body)
+        .chain([[3, 0]])
+        .flatten()
+        .map(|n| n.to_string())
+        .collect();
+
+    let digits = rng.range(1..=4) as u32;
+    let a = rng.range(8u64.pow(digits - 1)..=8u64.pow(digits) - 1);
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+        program.join(",")
+    )
+}
+
+/// Door codes for day 21, three digits and then an `A`.
+pub fn keypad_codes(rng: &mut Rng) -> String {
+    let codes: Vec<_> = (0..rng.range(1..=5))
+        .map(|_| format!("{:03}A", rng.range(1..=999)))
+        .collect();
+    codes.join("\n")
+}
+
+/// A network of computers for day 23, with names that are two lowercase letters and about a
+/// third of them starting with `t`. There's always at least one connection.
+pub fn network(rng: &mut Rng) -> String {
+    let count = rng.range(2..=12) as usize;
+    let mut names: Vec<String> = Vec::with_capacity(count);
+    while names.len() < count {
+        let first = if rng.chance(0.3) {
+            't'
+        } else {
+            (b'a' + rng.below(26) as u8) as char
+        };
+        let name = format!("{first}{}", (b'a' + rng.below(26) as u8) as char);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let density = rng.range(2..=8) as f64 / 10.0;
+    let mut links = vec![(0, 1)];
+    for a in 0..count {
+        for b in a + 1..count {
+            if (a, b) != (0, 1) && rng.chance(density) {
+                links.push((a, b));
+            }
+        }
+    }
+    rng.shuffle(&mut links);
+
+    let mut input = String::new();
+    for (a, b) in links {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+        writeln!(input, "{}-{}", names[a], names[b]).unwrap();
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn ranges_stay_in_bounds_and_hit_both_ends() {
+        let mut rng = Rng::new(1);
+        let picks: Vec<_> = (0..1000).map(|_| rng.range(3..=6)).collect();
+        assert!(picks.iter().all(|n| (3..=6).contains(n)));
+        assert!(picks.contains(&3) && picks.contains(&6));
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed with seed")]
+    fn failing_properties_report_the_seed() {
+        check(10, |rng| assert!(rng.below(4) != 0));
+    }
+}
@@ -0,0 +1,213 @@
+//! Finding each day's inputs. Inputs live in `day-NN/<name>.txt` under a data directory, where the
+//! real input is named `real` and kept in `input.txt`. The repo itself is always searched last,
+//! so the examples are found even when the real inputs are kept somewhere else.
+
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+/// Environment variable pointing at the directory to look for inputs in before the repo.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// The name of the real input for each day.
+pub const REAL: &str = "real";
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("no input named {name:?} for day {day:02}, tried {}", list_paths(.tried))]
+    NotFound {
+        day: u8,
+        name: String,
+        tried: Vec<PathBuf>,
+    },
+    #[error("{} doesn't exist", .0.display())]
+    NoSuchFile(PathBuf),
+    #[error("failed to read {}", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to read stdin")]
+    Stdin(#[source] anyhow::Error),
+}
+
+fn list_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Which input to use, either by name, as a path to a file, or piped in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Named(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Named(REAL.to_string())
+    }
+}
+
+/// `-` is stdin, anything that looks like a path, so has a separator or a `.txt` on the end, is
+/// taken as a file, and anything else as a name.
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            Ok(InputSource::Stdin)
+        } else if s.contains(['/', '\\']) || s.ends_with(".txt") {
+            Ok(InputSource::File(PathBuf::from(s)))
+        } else {
+            Ok(InputSource::Named(s.to_string()))
+        }
+    }
+}
+
+/// An input that's been read, and the file it came from if it didn't come from stdin.
+#[derive(Debug, Clone)]
+pub struct LoadedInput {
+    pub path: Option<PathBuf>,
+    pub contents: String,
+}
+
+impl Display for LoadedInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display()),
+            None => write!(f, "stdin"),
+        }
+    }
+}
+
+/// The directories inputs get looked for in, in order.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dirs: Vec<PathBuf>,
+}
+
+impl Inputs {
+    /// Looks in `$AOC_DATA_DIR` if it's set, then the repo.
+    pub fn from_env() -> Self {
+        Self::new(env::var_os(DATA_DIR_VAR).map(PathBuf::from))
+    }
+
+    /// Looks in `data_dir` if there is one, then the repo.
+    pub fn new(data_dir: Option<PathBuf>) -> Self {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("common should be in the repo")
+            .to_path_buf();
+        Self {
+            dirs: data_dir.into_iter().chain([repo]).collect(),
+        }
+    }
+
+    pub fn dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    /// Where the day's input with the given name is, if it's anywhere.
+    pub fn find(&self, day: u8, name: &str) -> Result<PathBuf, InputError> {
+        let tried: Vec<_> = self
+            .dirs
+            .iter()
+            .map(|dir| dir.join(format!("day-{day:02}")).join(file_name(name)))
+            .collect();
+
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                day,
+                name: name.to_string(),
+                tried,
+            }),
+        }
+    }
+
+    /// Where the input is, or `None` for stdin.
+    pub fn resolve(&self, day: u8, source: &InputSource) -> Result<Option<PathBuf>, InputError> {
+        match source {
+            InputSource::Named(name) => self.find(day, name).map(Some),
+            InputSource::File(path) if path.is_file() => Ok(Some(path.clone())),
+            InputSource::File(path) => Err(InputError::NoSuchFile(path.clone())),
+            InputSource::Stdin => Ok(None),
+        }
+    }
+
+    /// Finds and reads an input. Stdin can only be read once, so the same input can't be read from
+    /// it twice.
+    pub fn read(&self, day: u8, source: &InputSource) -> Result<LoadedInput, InputError> {
+        let path = self.resolve(day, source)?;
+        let contents = match &path {
+            Some(path) => read_to_string(path).map_err(|source| InputError::Read {
+                path: path.clone(),
+                source,
+            })?,
+            None => crate::read_all(io::stdin().lock()).map_err(InputError::Stdin)?,
+        };
+
+        Ok(LoadedInput { path, contents })
+    }
+}
+
+fn file_name(name: &str) -> String {
+    match name {
+        REAL => "input.txt".to_string(),
+        name => format!("{name}.txt"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_are_names_unless_they_look_like_paths() {
+        let parse = |s: &str| s.parse::<InputSource>().unwrap();
+        assert_eq!(
+            parse("example2"),
+            InputSource::Named("example2".to_string())
+        );
+        assert_eq!(parse("in.txt"), InputSource::File(PathBuf::from("in.txt")));
+        assert_eq!(parse("-"), InputSource::Stdin);
+        assert_eq!(
+            parse("../inputs/18"),
+            InputSource::File(PathBuf::from("../inputs/18"))
+        );
+    }
+
+    #[test]
+    fn names_are_found_in_the_repo() {
+        let inputs = Inputs::new(None);
+        let path = inputs.find(18, "example").unwrap();
+        assert!(path.ends_with("day-18/example.txt"));
+    }
+
+    #[test]
+    fn missing_inputs_list_every_path_tried() {
+        let inputs = Inputs::new(Some(PathBuf::from("/nowhere")));
+        let err = inputs.find(3, "example9").unwrap_err().to_string();
+
+        assert!(
+            err.starts_with("no input named \"example9\" for day 03"),
+            "{err}"
+        );
+        assert!(err.contains("/nowhere/day-03/example9.txt"), "{err}");
+        assert!(err.contains("day-03/example9.txt"), "{err}");
+        assert_eq!(err.matches("example9.txt").count(), 2, "{err}");
+    }
+}
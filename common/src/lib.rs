@@ -0,0 +1,143 @@
+use std::{
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, Read},
+    path::Path,
+};
+
+use anyhow::{anyhow, Context};
+
+pub mod bench;
+pub mod generate;
+pub mod inputs;
+pub mod params;
+pub mod parse;
+
+/// A single day's puzzle, split into parsing and solving so each part can run off of the same
+/// parsed input.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+    /// Anything besides the input the puzzle needs, use [`params::NoParams`] if there's nothing.
+    type Params: params::Params;
+
+    fn parse(input: &str, params: &Self::Params) -> Result<Self::Input, anyhow::Error>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Parses an input that's already in memory with the day's default parameters, so tests can
+/// write small inputs inline.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Input, anyhow::Error> {
+    S::parse(input, &S::Params::default())
+}
+
+/// Reads a whole input from anything readable, like stdin, and parses it.
+pub fn read_from<S: Solution>(
+    reader: impl Read,
+    params: &S::Params,
+) -> Result<S::Input, anyhow::Error> {
+    let input = read_all(reader)?;
+    S::parse(&input, params)
+}
+
+/// Reads all of an input, giving a clearer error than the bare io one for input that isn't text.
+pub fn read_all(mut reader: impl Read) -> Result<String, anyhow::Error> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidData => anyhow!("input isn't valid UTF-8"),
+            _ => anyhow::Error::new(e).context("failed to read input"),
+        })?;
+    Ok(input)
+}
+
+/// Reads and parses an input, using the parameters from the `.toml` file next to it if there is
+/// one.
+pub fn read_input<S: Solution>(path: impl AsRef<Path>) -> Result<S::Input, anyhow::Error> {
+    let path = path.as_ref();
+    let input =
+        read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let params = params::load(path)
+        .and_then(params::from_table)
+        .with_context(|| format!("failed to load the parameters for {}", path.display()))?;
+    S::parse(&input, &params)
+}
+
+/// Finds the day's input with the given name, like `example` or `real`, and reads it. See
+/// [`inputs`] for where it gets looked for.
+pub fn load_input<S: Solution>(day: u8, name: &str) -> Result<S::Input, anyhow::Error> {
+    let path = inputs::Inputs::from_env().find(day, name)?;
+    read_input::<S>(path)
+}
+
+/// Parses the input once and returns the answer for each of the requested parts.
+pub fn solve<S: Solution>(
+    input: &str,
+    params: &S::Params,
+    parts: &[u8],
+) -> Result<Vec<String>, anyhow::Error> {
+    let parsed = S::parse(input, params)?;
+
+    parts
+        .iter()
+        .map(|part| match part {
+            1 => Ok(S::part1(&parsed).to_string()),
+            2 => Ok(S::part2(&parsed).to_string()),
+            _ => Err(anyhow!("there is no part {part}")),
+        })
+        .collect()
+}
+
+/// Entrypoint for the individual day binaries, prints the answers to both parts.
+pub fn print_answers<S: Solution>(day: u8) {
+    let input = load_input::<S>(day, inputs::REAL).expect("failed to read input");
+    println!("Part 1: {}", S::part1(&input));
+    println!("Part 2: {}", S::part2(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One number per line, with their sum and product as the answers.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+        type Params = params::NoParams;
+
+        fn parse(input: &str, _: &params::NoParams) -> Result<Self::Input, anyhow::Error> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> u64 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> u64 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn inputs_can_come_from_memory_or_readers() {
+        let parsed = parse_input::<Sum>("1\n2\n3").unwrap();
+        assert_eq!(Sum::part1(&parsed), 6);
+
+        let parsed = read_from::<Sum>("4\n5\n".as_bytes(), &params::NoParams {}).unwrap();
+        assert_eq!(Sum::part2(&parsed), 20);
+    }
+
+    #[test]
+    fn non_utf8_input_is_an_error() {
+        let err = read_all([0xff, 0xfe].as_slice()).unwrap_err();
+        assert_eq!(err.to_string(), "input isn't valid UTF-8");
+    }
+}
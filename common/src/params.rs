@@ -0,0 +1,128 @@
+//! Puzzle parameters, for the numbers a day needs besides its input, like how big the grid is or
+//! how many times to blink. Examples usually want smaller ones than the real inputs, so they can
+//! be set per input in a `.toml` file next to it, or overridden from the command line.
+
+use std::{fmt::Debug, fs::read_to_string, io::ErrorKind, path::Path};
+
+use anyhow::{anyhow, Context};
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// A day's parameter set. `Default` should give the values for the real inputs.
+pub trait Params: Default + Debug + DeserializeOwned {}
+
+impl<T: Default + Debug + DeserializeOwned> Params for T {}
+
+/// For days that don't take any parameters. Trying to set one is an error, so typos don't go
+/// unnoticed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// A parsed input alongside the parameters it was parsed with, for days whose parts need them
+/// too.
+#[derive(Debug, Clone)]
+pub struct WithParams<T, P> {
+    pub input: T,
+    pub params: P,
+}
+
+/// The parameters set for an input, read from the `.toml` file with the same name as it.
+/// Inputs without one get an empty table, so the defaults get used.
+pub fn load(input_path: &Path) -> Result<toml::Table, anyhow::Error> {
+    let path = input_path.with_extension("toml");
+    match read_to_string(&path) {
+        Ok(contents) => {
+            toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(toml::Table::new()),
+        Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Sets one parameter from a `name=value` argument. The value is read as TOML if it can be, so
+/// `size=7` is a number, and falls back to a plain string otherwise.
+pub fn set_override(params: &mut toml::Table, arg: &str) -> Result<(), anyhow::Error> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected name=value, got {arg:?}"))?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() {
+        return Err(anyhow!("missing a parameter name in {arg:?}"));
+    }
+
+    let value = toml::from_str::<toml::Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()));
+    params.insert(name.to_string(), value);
+
+    Ok(())
+}
+
+/// Turns a table of parameters into a day's own type. Anything missing from the table keeps its
+/// default, as long as the type is marked `#[serde(default)]`.
+pub fn from_table<P: Params>(params: toml::Table) -> Result<P, anyhow::Error> {
+    if params.is_empty() {
+        return Ok(P::default());
+    }
+
+    toml::Value::Table(params)
+        .try_into()
+        .context("invalid puzzle parameters")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Example {
+        size: usize,
+        name: String,
+    }
+
+    impl Default for Example {
+        fn default() -> Self {
+            Self {
+                size: 71,
+                name: "real".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn overrides_keep_other_defaults() {
+        let mut params = toml::Table::new();
+        set_override(&mut params, "size=7").unwrap();
+
+        let example: Example = from_table(params).unwrap();
+        assert_eq!(
+            example,
+            Example {
+                size: 7,
+                name: "real".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn bare_words_are_strings() {
+        let mut params = toml::Table::new();
+        set_override(&mut params, "name = example").unwrap();
+
+        let example: Example = from_table(params).unwrap();
+        assert_eq!(example.name, "example");
+    }
+
+    #[test]
+    fn bad_overrides_are_errors() {
+        assert!(set_override(&mut toml::Table::new(), "size").is_err());
+        assert!(set_override(&mut toml::Table::new(), "=7").is_err());
+
+        let mut params = toml::Table::new();
+        set_override(&mut params, "sise=7").unwrap();
+        assert!(from_table::<Example>(params.clone()).is_err());
+        assert!(from_table::<NoParams>(params).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// An error from parsing a puzzle input, pointing at where in the input it went wrong.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line}, column {col}: {kind}")]
+pub struct ParseError {
+    /// 1-indexed line of the input.
+    pub line: usize,
+    /// 1-indexed column, counted in chars.
+    pub col: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    #[error("unexpected character {0:?}")]
+    UnexpectedChar(char),
+    #[error("invalid number {0:?}")]
+    InvalidNumber(String),
+    #[error("expected {0}")]
+    Expected(String),
+    #[error("unexpected end of input, expected {0}")]
+    UnexpectedEnd(String),
+}
+
+impl ParseError {
+    pub fn new(line: usize, col: usize, kind: ParseErrorKind) -> Self {
+        Self { line, col, kind }
+    }
+
+    /// Points the error at the start of `at`, which has to be a slice of `input`.
+    pub fn at(input: &str, at: &str, kind: ParseErrorKind) -> Self {
+        let (line, col) = position(input, at);
+        Self::new(line, col, kind)
+    }
+
+    /// Points the error at the end of `s`, a slice of `input`, for when it stops before something
+    /// that was expected.
+    pub fn end_of(input: &str, s: &str, expected: &str) -> Self {
+        Self::at(
+            input,
+            &s[s.len()..],
+            ParseErrorKind::UnexpectedEnd(expected.to_string()),
+        )
+    }
+
+    /// Shifts the error down by `lines`, for when the text that was parsed was only part of the
+    /// whole input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+/// Finds the 1-indexed line and column that `at` starts at, where `at` is a slice of `input`.
+fn position(input: &str, at: &str) -> (usize, usize) {
+    let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    debug_assert!(offset <= input.len(), "`at` must be a slice of `input`");
+    let before = &input[..offset.min(input.len())];
+
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Parses `s`, a slice of `input`, as a number.
+pub fn num<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.parse()
+        .map_err(|_| ParseError::at(input, s, ParseErrorKind::InvalidNumber(s.to_string())))
+}
+
+/// Splits `s`, a slice of `input`, on the first `delim`. The error points at the end of `s` if
+/// there's no `delim` in it.
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    delim: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delim).ok_or_else(|| {
+        ParseError::at(
+            input,
+            &s[s.len()..],
+            ParseErrorKind::Expected(format!("{delim:?}")),
+        )
+    })
+}
+
+/// Strips `prefix` off of `s`, a slice of `input`.
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, s, ParseErrorKind::Expected(format!("{prefix:?}"))))
+}
+
+/// Parses every non-blank line of `input` on its own.
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.offset_lines(i)))
+        .collect()
+}
+
+/// Parses every block of lines in `input` on its own, where blocks are separated by a blank line.
+pub fn blocks<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| {
+            let (line, _) = position(input, block);
+            block
+                .parse()
+                .map_err(|e: ParseError| e.offset_lines(line - 1))
+        })
+        .collect()
+}
+
+/// Parses every char of a grid with `f`, pointing at the char if it fails. Blank lines are skipped,
+/// and every row has to be as wide as the first one.
+pub fn char_grid<T>(
+    input: &str,
+    mut f: impl FnMut(char) -> Result<T, ParseErrorKind>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(y, line)| {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, c)| f(c).map_err(|kind| ParseError::new(y + 1, x + 1, kind)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(ParseError::new(
+                    y + 1,
+                    width.min(row.len()) + 1,
+                    ParseErrorKind::Expected(format!("{width} columns")),
+                ));
+            }
+
+            Ok(row)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_points_at_the_slice() {
+        let input = "ab\ncd\nef";
+        let err = ParseError::at(input, &input[7..], ParseErrorKind::UnexpectedChar('f'));
+        assert_eq!((err.line, err.col), (3, 2));
+    }
+
+    #[test]
+    fn blocks_offset_errors_to_the_whole_input() {
+        let input = "1\n2\n\n3\nx\n";
+        let err = blocks::<Block>(input).unwrap_err();
+        assert_eq!((err.line, err.col), (5, 1));
+    }
+
+    #[test]
+    fn char_grid_rejects_ragged_rows() {
+        let err = char_grid("ab\nc\n", Ok).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 2, ParseErrorKind::Expected("2 columns".to_string()))
+        );
+    }
+
+    #[derive(Debug)]
+    struct Block;
+
+    impl FromStr for Block {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            lines::<Num>(s).map(|_| Block)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Num;
+
+    impl FromStr for Num {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            num::<u8>(s, s).map(|_| Num)
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i64>, Vec<i64>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        let pairs: Vec<LocationIds> = parse::lines(input)?;
+        Ok(pairs.into_iter().map(|LocationIds(a, b)| (a, b)).unzip())
+    }
+
+    fn part1((list1, list2): &Self::Input) -> i64 {
+        let mut list1 = list1.clone();
+        let mut list2 = list2.clone();
+        list1.sort();
+        list2.sort();
+
+        list1
+            .iter()
+            .enumerate()
+            .map(|(i, num)| (num - list2[i]).abs())
+            .sum()
+    }
+
+    fn part2((list1, list2): &Self::Input) -> i64 {
+        let right_appearances = list2.iter().fold(HashMap::new(), |mut acc, num| {
+            acc.insert(num, acc.get(num).map(|cur| cur + 1).unwrap_or(1));
+            acc
+        });
+
+        list1
+            .iter()
+            .map(|num| num * right_appearances.get(num).unwrap_or(&0))
+            .sum()
+    }
+}
+
+/// One line of the input, with an id from each list.
+struct LocationIds(i64, i64);
+
+impl FromStr for LocationIds {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut nums = line.split_ascii_whitespace();
+        let mut next_num = || match nums.next() {
+            Some(n) => parse::num(line, n),
+            None => Err(ParseError::end_of(line, line, "a location id")),
+        };
+
+        Ok(Self(next_num()?, next_num()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let lists = load_input::<Day01>(1, "example").expect("failed to read input");
+
+        let result = Day01::part1(&lists);
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn part2_works() {
+        let lists = load_input::<Day01>(1, "example").expect("failed to read input");
+
+        let result = Day01::part2(&lists);
+        assert_eq!(result, 31);
+    }
+}
@@ -0,0 +1,72 @@
+use common::{params::NoParams, parse, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        let levels = input
+            .lines()
+            .map(|s| s.split_whitespace().map(|n| parse::num(input, n)).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(levels)
+    }
+
+    fn part1(levels: &Self::Input) -> i64 {
+        levels.iter().fold(0, |acc, floor| {
+            acc + if floor_is_safe(floor) { 1 } else { 0 }
+        })
+    }
+
+    fn part2(levels: &Self::Input) -> i64 {
+        levels.iter().fold(0, |acc, floor| {
+            acc + if floor_is_safe(floor) {
+                1
+            } else {
+                if (0..floor.len()).any(|i| {
+                    let floor_without_i = [&floor[0..i], &floor[i + 1..floor.len()]].concat();
+                    floor_is_safe(&floor_without_i)
+                }) {
+                    1
+                } else {
+                    0
+                }
+            }
+        })
+    }
+}
+
+fn floor_is_safe(floor: &[i64]) -> bool {
+    (floor.is_sorted_by(|a, b| a < b) || floor.is_sorted_by(|a, b| a > b))
+        && floor.windows(2).all(|window| {
+            let diff = (window[0] - window[1]).abs();
+            (1..=3).contains(&diff)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let levels = load_input::<Day02>(2, "example").expect("failed to read input");
+
+        let result = Day02::part1(&levels);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn part2_works() {
+        let levels = load_input::<Day02>(2, "example").expect("failed to read input");
+
+        let result = Day02::part2(&levels);
+        assert_eq!(result, 4);
+    }
+}
@@ -0,0 +1,104 @@
+use common::{params::NoParams, Solution};
+use regex::{Match, Regex};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let stmts = find_valid_multiply_stmts(input);
+        sum_muls(&stmts)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let stmts = find_enabled_multiply_stmts(input);
+        sum_muls(&stmts)
+    }
+}
+
+fn find_valid_multiply_stmts(input: &str) -> Vec<MultiplyStatement> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+    let match_to_int = |mat: Option<Match>| {
+        mat.expect("uncaptured num")
+            .as_str()
+            .parse()
+            .expect("capture was not an int")
+    };
+
+    re.captures_iter(input)
+        .map(|cap| MultiplyStatement {
+            left: match_to_int(cap.get(1)),
+            right: match_to_int(cap.get(2)),
+        })
+        .collect()
+}
+
+fn find_enabled_multiply_stmts(input: &str) -> Vec<MultiplyStatement> {
+    let mut stmts = Vec::new();
+
+    find_enabled_multiply_stmts_helper(&mut stmts, input);
+
+    stmts
+}
+
+fn find_enabled_multiply_stmts_helper(acc: &mut Vec<MultiplyStatement>, input: &str) {
+    let (on, off) = match input.split_once("don't()") {
+        Some((a, b)) => (a, b),
+        None => (input, ""),
+    };
+    let mut stmts = find_valid_multiply_stmts(on);
+    acc.append(&mut stmts);
+
+    if let Some((_, back_on)) = off.split_once("do()") {
+        find_enabled_multiply_stmts_helper(acc, back_on)
+    }
+}
+
+struct MultiplyStatement {
+    left: u32,
+    right: u32,
+}
+
+impl MultiplyStatement {
+    fn mul(&self) -> u32 {
+        self.left * self.right
+    }
+}
+
+fn sum_muls(stmts: &[MultiplyStatement]) -> u32 {
+    stmts.iter().fold(0, |acc, s| acc + s.mul())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day03>(3, "example").expect("failed to read example");
+        let result = Day03::part1(&input);
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = load_input::<Day03>(3, "example").expect("failed to read example");
+        let result = Day03::part2(&input);
+        assert_eq!(result, 161);
+
+        let input2 = load_input::<Day03>(3, "example2").expect("failed to read example");
+        let result2 = Day03::part2(&input2);
+        assert_eq!(result2, 48);
+    }
+}
@@ -0,0 +1,141 @@
+use common::{params::NoParams, parse::ParseErrorKind, Solution};
+use grid::{Direction8, Grid, Point};
+use strum::IntoEnumIterator;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<Letter>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(Grid::parse(input, Letter::try_from)?)
+    }
+
+    fn part1(letters: &Self::Input) -> u64 {
+        num_of_xmas_in_word_search(letters)
+    }
+
+    fn part2(letters: &Self::Input) -> u64 {
+        num_of_crossing_mas_in_word_search(letters)
+    }
+}
+
+fn num_of_xmas_in_word_search(letters: &Grid<Letter>) -> u64 {
+    letters
+        .points()
+        .map(|coor| num_xmas_from_letter(letters, coor, Letter::X, None))
+        .sum()
+}
+
+fn num_xmas_from_letter(
+    letters: &Grid<Letter>,
+    coor: Point,
+    needed_letter: Letter,
+    cur_direction: Option<Direction8>,
+) -> u64 {
+    let cur_letter = letters[coor];
+
+    if cur_letter != needed_letter {
+        return 0;
+    }
+
+    match cur_letter {
+        Letter::S => 1,
+        Letter::X => Direction8::iter()
+            .map(|dir| move_to_next_letter(letters, coor, Letter::M, dir))
+            .sum(),
+        _ => {
+            let dir = cur_direction.expect("should already have a known direction for M and A");
+            move_to_next_letter(
+                letters,
+                coor,
+                match cur_letter {
+                    Letter::M => Letter::A,
+                    Letter::A => Letter::S,
+                    _ => panic!("can't get here"),
+                },
+                dir,
+            )
+        }
+    }
+}
+
+fn move_to_next_letter(
+    letters: &Grid<Letter>,
+    coor: Point,
+    needed_letter: Letter,
+    dir: Direction8,
+) -> u64 {
+    match letters.neighbor8(coor, dir) {
+        Some(new_coor) => num_xmas_from_letter(letters, new_coor, needed_letter, Some(dir)),
+        None => 0,
+    }
+}
+
+fn num_of_crossing_mas_in_word_search(letters: &Grid<Letter>) -> u64 {
+    letters
+        .iter()
+        .filter(|(coor, letter)| {
+            **letter == Letter::A
+                && is_valid_mas(letters, *coor, [Direction8::DownLeft, Direction8::UpRight])
+                && is_valid_mas(letters, *coor, [Direction8::DownRight, Direction8::UpLeft])
+        })
+        .count() as u64
+}
+
+fn is_valid_mas(letters: &Grid<Letter>, coor: Point, directions: [Direction8; 2]) -> bool {
+    let neighbors = directions.map(|dir| letters.neighbor8(coor, dir).map(|n| letters[n]));
+
+    matches!(
+        neighbors,
+        [Some(Letter::M), Some(Letter::S)] | [Some(Letter::S), Some(Letter::M)]
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Letter {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Letter {
+    type Error = ParseErrorKind;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
+        match item {
+            'X' => Ok(Self::X),
+            'M' => Ok(Self::M),
+            'A' => Ok(Self::A),
+            'S' => Ok(Self::S),
+            _ => Err(ParseErrorKind::UnexpectedChar(item)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let letters = load_input::<Day04>(4, "example").expect("failed to read input");
+        let result = Day04::part1(&letters);
+
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn part2_works() {
+        let letters = load_input::<Day04>(4, "example").expect("failed to read input");
+        let result = Day04::part2(&letters);
+
+        assert_eq!(result, 9);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PuzzleInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input
+            .pages_to_produce
+            .iter()
+            .map(|p_order| {
+                if page_order_is_correct(p_order, &input.pages_not_allowed_before) {
+                    get_mid_number(p_order)
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        input
+            .pages_to_produce
+            .iter()
+            .filter_map(|p_order| {
+                if page_order_is_correct(p_order, &input.pages_not_allowed_before) {
+                    None
+                } else {
+                    let sorted = sort_page_order(p_order, &input.pages_not_allowed_before);
+                    Some(get_mid_number(&sorted))
+                }
+            })
+            .sum()
+    }
+}
+
+fn parse_rules(input: &str, rules_str: &str) -> Result<RulesMap, ParseError> {
+    let mut rules: RulesMap = HashMap::new();
+    for line in rules_str.lines() {
+        let (a, b) = parse::split_once(input, line, "|")?;
+        let (a_int, b_int) = (parse::num(input, a)?, parse::num(input, b)?);
+        if let Some(cur_list) = rules.get_mut(&a_int) {
+            cur_list.push(b_int);
+        } else {
+            let new_list = vec![b_int];
+            rules.insert(a_int, new_list);
+        }
+    }
+    Ok(rules)
+}
+
+fn parse_pages_to_produce(input: &str, pages_str: &str) -> Result<Vec<PageOrder>, ParseError> {
+    pages_str
+        .lines()
+        .map(|line| line.split(",").map(|n| parse::num(input, n)).collect())
+        .collect()
+}
+
+type PageOrder = Vec<u64>;
+type RulesMap = HashMap<u64, Vec<u64>>;
+
+pub struct PuzzleInput {
+    pages_not_allowed_before: RulesMap,
+    pages_to_produce: Vec<PageOrder>,
+}
+
+impl FromStr for PuzzleInput {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (rules, page_orders) = parse::split_once(input, input, "\n\n")?;
+
+        Ok(PuzzleInput {
+            pages_not_allowed_before: parse_rules(input, rules)?,
+            pages_to_produce: parse_pages_to_produce(input, page_orders)?,
+        })
+    }
+}
+
+fn get_mid_number(l: &PageOrder) -> u64 {
+    l[l.len() / 2]
+}
+
+fn page_order_is_correct(p_order: &PageOrder, pages_not_allowed_before: &RulesMap) -> bool {
+    p_order
+        .iter()
+        .enumerate()
+        .all(|(i, page)| page_is_allowed(&p_order[0..i], page, pages_not_allowed_before))
+}
+
+fn page_is_allowed(prev_pages: &[u64], page: &u64, pages_not_allowed_before: &RulesMap) -> bool {
+    if let Some(unallowed_list) = pages_not_allowed_before.get(page) {
+        prev_pages
+            .iter()
+            .all(|prev_page| !unallowed_list.contains(prev_page))
+    } else {
+        true
+    }
+}
+
+fn sort_page_order(p_order: &PageOrder, pages_not_allowed_before: &RulesMap) -> PageOrder {
+    let mut sorted_page_order = Vec::with_capacity(p_order.len());
+
+    p_order.iter().enumerate().for_each(|(i, page)| {
+        let mut j = i;
+
+        loop {
+            if page_is_allowed(&sorted_page_order[0..j], page, pages_not_allowed_before) {
+                sorted_page_order.insert(j, *page);
+                break;
+            }
+            j -= 1;
+        }
+    });
+
+    sorted_page_order
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let puzzle_input = load_input::<Day05>(5, "example").expect("failed to parse input");
+        let result = Day05::part1(&puzzle_input);
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn sort_works() {
+        let puzzle_input = load_input::<Day05>(5, "example").expect("failed to parse input");
+        puzzle_input.pages_to_produce.iter().for_each(|p_order| {
+            let sorted = sort_page_order(p_order, &puzzle_input.pages_not_allowed_before);
+            assert!(page_order_is_correct(
+                &sorted,
+                &puzzle_input.pages_not_allowed_before
+            ));
+        });
+    }
+
+    #[test]
+    fn part2_works() {
+        let puzzle_input = load_input::<Day05>(5, "example").expect("failed to parse input");
+        let result = Day05::part2(&puzzle_input);
+        assert_eq!(result, 123);
+    }
+}
@@ -0,0 +1,335 @@
+use std::{fmt::Display, io, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{BitGrid, Color, DirectedBitGrid, Direction, Frames, Grid, Image, Point, Rgb};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        let mut map = map.clone();
+        map.move_guard_until_off_map();
+        map.num_visited_spaces()
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        map.grid.iter().fold(0, |acc, (point, obj)| {
+            if matches!(obj, GridObject::Empty) {
+                let mut cloned_map = map.clone();
+                cloned_map.grid[point] = GridObject::Obstruction;
+                if cloned_map.grid_results_in_cycle() {
+                    acc + 1
+                } else {
+                    acc
+                }
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    grid: Grid<GridObject>,
+    visited: BitGrid,
+    cur_guard_pos: Point,
+}
+
+impl Map {
+    fn num_visited_spaces(&self) -> u64 {
+        self.visited.count() as u64
+    }
+
+    fn grid_results_in_cycle(&mut self) -> bool {
+        let mut visited_spaces = DirectedBitGrid::new(self.grid.width(), self.grid.height());
+
+        loop {
+            if !visited_spaces.insert(self.cur_guard_pos, self.get_guard_dir()) {
+                return true;
+            }
+
+            if !self.move_guard() {
+                return false;
+            }
+        }
+    }
+
+    /// Writes a frame of the lab for every move the guard makes, with the spots they've been to
+    /// filled in. Stops when they walk off the map, or when they get back to somewhere they've
+    /// already been facing the same way, since after that they just go round in circles.
+    pub fn record_walk(&self, frames: &mut Frames) -> io::Result<()> {
+        let mut map = self.clone();
+        let mut poses = DirectedBitGrid::new(map.grid.width(), map.grid.height());
+        poses.insert(map.cur_guard_pos, map.get_guard_dir());
+        frames.push(&map.to_image())?;
+
+        while map.move_guard() && poses.insert(map.cur_guard_pos, map.get_guard_dir()) {
+            frames.push(&map.to_image())?;
+        }
+
+        Ok(())
+    }
+
+    fn to_image(&self) -> Image {
+        let cells = Grid::from_fn(self.grid.width(), self.grid.height(), |p| {
+            (self.grid[p], self.visited[p])
+        });
+
+        cells.to_image(|(obj, visited)| match obj {
+            GridObject::Obstruction => Rgb::GRAY,
+            GridObject::Guard(_) => Color::Red.into(),
+            GridObject::Empty if *visited => Color::Blue.into(),
+            GridObject::Empty => Rgb::BLACK,
+        })
+    }
+
+    fn move_guard_until_off_map(&mut self) {
+        loop {
+            if !self.move_guard() {
+                break;
+            }
+        }
+    }
+
+    fn move_guard(&mut self) -> bool {
+        match self.get_obj_in_front_of_guard() {
+            Some((dir, point, obj)) => {
+                match obj {
+                    GridObject::Empty => {
+                        self.grid[point] = GridObject::Guard(dir);
+                        self.visited.insert(point);
+                        self.grid[self.cur_guard_pos] = GridObject::Empty;
+                        self.cur_guard_pos = point;
+                    }
+                    GridObject::Obstruction => {
+                        self.grid[self.cur_guard_pos] = GridObject::Guard(dir.turn_right());
+                    }
+                    _ => panic!("invalid obj in front of guard"),
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_guard_dir(&self) -> Direction {
+        let obj = &self.grid[self.cur_guard_pos];
+        match obj {
+            GridObject::Guard(dir) => *dir,
+            _ => panic!("obj at guard pos is not the guard"),
+        }
+    }
+
+    fn get_obj_in_front_of_guard(&self) -> Option<(Direction, Point, GridObject)> {
+        let dir = self.get_guard_dir();
+
+        self.grid
+            .neighbor(self.cur_guard_pos, dir)
+            .map(|point| (dir, point, self.grid[point]))
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(value, GridObject::try_from)?;
+
+        let guards: Vec<_> = grid
+            .iter()
+            .filter(|(_, obj)| matches!(obj, GridObject::Guard(_)))
+            .map(|(p, _)| p)
+            .collect();
+        let cur_guard_pos = match guards[..] {
+            [] => return Err(ParseError::end_of(value, value, "a guard")),
+            [guard] => guard,
+            [_, second_guard, ..] => {
+                return Err(grid.parse_error_at(
+                    value,
+                    second_guard,
+                    ParseErrorKind::Expected("only one guard".to_string()),
+                ))
+            }
+        };
+
+        let mut visited = BitGrid::new(grid.width(), grid.height());
+        visited.insert(cur_guard_pos);
+
+        Ok(Self {
+            grid,
+            visited,
+            cur_guard_pos,
+        })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid.render())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GridObject {
+    Empty,
+    Guard(Direction),
+    Obstruction,
+}
+
+impl TryFrom<char> for GridObject {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(GridObject::Empty),
+            '#' => Ok(GridObject::Obstruction),
+            _ => Direction::from_arrow(value)
+                .map(GridObject::Guard)
+                .ok_or(ParseErrorKind::UnexpectedChar(value)),
+        }
+    }
+}
+
+impl From<GridObject> for char {
+    fn from(value: GridObject) -> Self {
+        match value {
+            GridObject::Empty => '.',
+            GridObject::Guard(dir) => dir.arrow(),
+            GridObject::Obstruction => '#',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs::read_to_string, path::PathBuf};
+
+    use common::{inputs::Inputs, load_input, read_input};
+
+    use super::*;
+
+    #[test]
+    fn map_back_and_forth_to_str() {
+        let path = Inputs::from_env()
+            .find(6, "example")
+            .expect("no example input");
+        let raw_example = read_to_string(&path).expect("read input failed");
+        let input = read_input::<Day06>(&path).expect("read input failed");
+
+        assert_eq!(input.to_string(), raw_example.trim());
+    }
+
+    /// A directory for frames that's removed again even if the test fails.
+    struct FramesDir(PathBuf);
+
+    impl FramesDir {
+        fn new(name: &str) -> Self {
+            Self(std::env::temp_dir().join(format!("day-06-{name}-{}", std::process::id())))
+        }
+    }
+
+    impl Drop for FramesDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn record_walk_writes_a_frame_per_move() {
+        let map = load_input::<Day06>(6, "example").expect("read input failed");
+        let dir = FramesDir::new("frames");
+        let mut frames = Frames::new(&dir.0)
+            .expect("failed to make frames dir")
+            .format(grid::ImageFormat::Ppm);
+
+        map.record_walk(&mut frames)
+            .expect("failed to write frames");
+
+        let mut walked = map.clone();
+        let mut moves = 0;
+        while walked.move_guard() {
+            moves += 1;
+        }
+        assert_eq!(frames.len(), moves + 1);
+
+        let first = std::fs::read(dir.0.join("frame_00000.ppm")).expect("failed to read frame");
+        assert!(first.starts_with(b"P6\n10 10\n255\n"));
+    }
+
+    #[test]
+    fn record_walk_stops_when_the_guard_goes_round_in_circles() {
+        let map = load_input::<Day06>(6, "example_cycle").expect("read input failed");
+        let dir = FramesDir::new("cycle-frames");
+        let mut frames = Frames::new(&dir.0)
+            .expect("failed to make frames dir")
+            .format(grid::ImageFormat::Ppm);
+
+        map.record_walk(&mut frames)
+            .expect("failed to write frames");
+
+        let mut walked = map.clone();
+        let mut poses = HashSet::from([(walked.cur_guard_pos, walked.get_guard_dir())]);
+        while walked.move_guard() && poses.insert((walked.cur_guard_pos, walked.get_guard_dir())) {}
+        assert_eq!(frames.len(), poses.len());
+    }
+
+    #[test]
+    fn a_second_guard_is_an_error() {
+        let err = "..^.\n....\n.>..".parse::<Map>().err().unwrap();
+        assert_eq!(err.to_string(), "line 3, column 2: expected only one guard");
+    }
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day06>(6, "example").expect("read input failed");
+        let result = Day06::part1(&input);
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = load_input::<Day06>(6, "example").expect("read input failed");
+        let result = Day06::part2(&input);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn cloning_board_works() {
+        let mut input = load_input::<Day06>(6, "example").expect("read input failed");
+        let cloned_input = input.clone();
+
+        let corner = Point { x: 0, y: 0 };
+        input.grid[corner] = GridObject::Obstruction;
+
+        assert_eq!(input.grid[corner], GridObject::Obstruction);
+        assert_eq!(cloned_input.grid[corner], GridObject::Empty);
+    }
+
+    #[test]
+    fn example_is_not_cycle() {
+        let mut input = load_input::<Day06>(6, "example").expect("read input failed");
+        let result = input.grid_results_in_cycle();
+        assert!(!result);
+    }
+
+    #[test]
+    fn example_cycle_is_cycle() {
+        let mut input = load_input::<Day06>(6, "example_cycle").expect("read input failed");
+        let result = input.grid_results_in_cycle();
+        assert!(result);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(parse::lines(input)?)
+    }
+
+    fn part1(equations: &Self::Input) -> i64 {
+        equations.iter().fold(0, |acc, eq| {
+            if eq.valid_with_any_operation_combos(&[Operation::Add, Operation::Multiply]) {
+                acc + eq.result
+            } else {
+                acc
+            }
+        })
+    }
+
+    fn part2(equations: &Self::Input) -> i64 {
+        equations.iter().fold(0, |acc, eq| {
+            if eq.valid_with_any_operation_combos(&[
+                Operation::Add,
+                Operation::Multiply,
+                Operation::Concat,
+            ]) {
+                acc + eq.result
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+enum Operation {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operation {
+    fn calculate(&self, a: i64, b: i64) -> i64 {
+        match self {
+            Operation::Add => a + b,
+            Operation::Multiply => a * b,
+            Operation::Concat => format!("{a}{b}").parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    result: i64,
+    nums: Vec<i64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+enum EquationError {
+    #[error("ops must be 1 less than nums")]
+    InvalidNumberOfOperations,
+}
+
+impl Equation {
+    fn valid_with_any_operation_combos(&self, op_options: &[Operation]) -> bool {
+        self.build_operation_combo_and_check_if_any_valid(&[], op_options)
+    }
+
+    fn build_operation_combo_and_check_if_any_valid(
+        &self,
+        ops: &[Operation],
+        op_options: &[Operation],
+    ) -> bool {
+        if ops.len() == self.nums.len() - 1 {
+            self.valid_with_operations(ops)
+                .expect("should never be the wrong size")
+        } else {
+            op_options.iter().any(|op| {
+                self.build_operation_combo_and_check_if_any_valid(
+                    &[ops, std::slice::from_ref(op)].concat(),
+                    op_options,
+                )
+            })
+        }
+    }
+
+    fn valid_with_operations(&self, ops: &[Operation]) -> Result<bool, EquationError> {
+        if ops.len() != self.nums.len() - 1 {
+            return Err(EquationError::InvalidNumberOfOperations);
+        }
+
+        let mut nums_stack: VecDeque<i64> = VecDeque::with_capacity(self.nums.len());
+        self.nums.iter().for_each(|n| nums_stack.push_front(*n));
+
+        let mut ops_stack = VecDeque::with_capacity(ops.len());
+        ops.iter().for_each(|op| ops_stack.push_front(op));
+
+        while nums_stack.len() > 1 {
+            let a = nums_stack.pop_back().expect("stack should never be empty");
+            let b = nums_stack.pop_back().expect("stack should never be empty");
+            let op = ops_stack.pop_back().expect("stack should never be empty");
+
+            let c = op.calculate(a, b);
+            nums_stack.push_back(c);
+        }
+
+        let actual = nums_stack.pop_back().expect("stack should never be empty");
+        Ok(actual == self.result)
+    }
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (left, right) = parse::split_once(value, value, ":")?;
+
+        let result = parse::num(value, left)?;
+        let nums = right
+            .split_whitespace()
+            .map(|s| parse::num(value, s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { result, nums })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input};
+
+    use super::*;
+
+    /// Tries every combination of operators by counting in base `operators`, with each digit
+    /// picking the operator between two of the numbers.
+    fn brute_force_total(input: &str, operators: u32) -> i64 {
+        input
+            .lines()
+            .map(|line| {
+                let (result, nums) = line.split_once(": ").unwrap();
+                let result: i64 = result.parse().unwrap();
+                let nums: Vec<i64> = nums.split(' ').map(|n| n.parse().unwrap()).collect();
+
+                let combos = operators.pow(nums.len() as u32 - 1);
+                let solvable = (0..combos).any(|mut combo| {
+                    let total = nums[1..].iter().fold(nums[0], |acc, n| {
+                        let op = combo % operators;
+                        combo /= operators;
+                        match op {
+                            0 => acc + n,
+                            1 => acc * n,
+                            _ => acc * 10i64.pow(n.to_string().len() as u32) + n,
+                        }
+                    });
+                    total == result
+                });
+
+                if solvable {
+                    result
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn totals_match_brute_force() {
+        generate::check(200, |rng| {
+            let input = generate::equations(rng);
+            let equations = parse_input::<Day07>(&input).unwrap();
+            assert_eq!(
+                Day07::part1(&equations),
+                brute_force_total(&input, 2),
+                "{input}"
+            );
+            assert_eq!(
+                Day07::part2(&equations),
+                brute_force_total(&input, 3),
+                "{input}"
+            );
+        });
+    }
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day07>(7, "example").expect("failed to read input");
+        let result = Day07::part1(&input);
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = load_input::<Day07>(7, "example").expect("failed to read input");
+        let result = Day07::part2(&input);
+        assert_eq!(result, 11387);
+    }
+
+    #[test]
+    fn parsing_equation_works() {
+        let result = "161011: 16 10 13".parse::<Equation>();
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            Equation {
+                result: 161011,
+                nums: vec![16, 10, 13]
+            }
+        );
+    }
+}
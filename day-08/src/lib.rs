@@ -0,0 +1,209 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Grid, Point};
+use itertools::Itertools;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        map.get_antinode_locations(false).len() as u64
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        map.get_antinode_locations(true).len() as u64
+    }
+}
+
+pub struct Map {
+    grid: Grid<GridObject>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(value, GridObject::try_from)?;
+
+        Ok(Self { grid })
+    }
+}
+
+impl Map {
+    fn get_antinode_locations(&self, allow_any_distance: bool) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+
+        let antennas = self.get_grouped_antennas();
+
+        antennas.iter().for_each(|(_, points)| {
+            self.get_antinodes_by_points(points, allow_any_distance)
+                .into_iter()
+                .for_each(|p| {
+                    antinodes.insert(p);
+                });
+        });
+
+        antinodes
+    }
+
+    fn get_antinodes_by_points(&self, points: &[Point], allow_any_distance: bool) -> Vec<Point> {
+        points
+            .iter()
+            .combinations(2)
+            .flat_map(|combo| {
+                self.get_antinodes_for_antennas(combo[0], combo[1], allow_any_distance)
+            })
+            .collect()
+    }
+
+    fn get_antinodes_for_antennas(
+        &self,
+        a: &Point,
+        b: &Point,
+        allow_any_distance: bool,
+    ) -> Vec<Point> {
+        let (a, b) = (a.to_vec2(), b.to_vec2());
+        let diff = b - a;
+
+        let mut antinodes = Vec::new();
+
+        let mut i = if allow_any_distance { 0 } else { 1 };
+        loop {
+            let mut new_antinodes: Vec<Point> = [a - diff * i, b + diff * i]
+                .into_iter()
+                .filter_map(|antinode| self.grid.checked_point(antinode))
+                .collect();
+            let new_antinodes_is_empty = new_antinodes.is_empty();
+
+            if !new_antinodes_is_empty {
+                antinodes.append(&mut new_antinodes);
+            }
+
+            if !allow_any_distance || new_antinodes_is_empty {
+                break;
+            }
+
+            i += 1;
+        }
+
+        antinodes
+    }
+
+    fn get_grouped_antennas(&self) -> HashMap<GridObject, Vec<Point>> {
+        let mut antenna_map: HashMap<GridObject, Vec<Point>> = HashMap::new();
+
+        self.grid.iter().for_each(|(point, obj)| {
+            if matches!(obj, GridObject::Antenna(_)) {
+                antenna_map.entry(*obj).or_default().push(point);
+            }
+        });
+
+        antenna_map
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+enum GridObject {
+    Antenna(char),
+    Empty,
+}
+
+impl TryFrom<char> for GridObject {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(GridObject::Empty),
+            _ if value.is_ascii_alphanumeric() => Ok(GridObject::Antenna(value)),
+            _ => Err(ParseErrorKind::UnexpectedChar(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day08>(8, "example").expect("failed to get input");
+        let result = Day08::part1(&input);
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn part2_works() {
+        let easy_input = load_input::<Day08>(8, "example_2").expect("failed to get input");
+        let easy_result = Day08::part2(&easy_input);
+        assert_eq!(easy_result, 9);
+
+        let input = load_input::<Day08>(8, "example").expect("failed to get input");
+        let result = Day08::part2(&input);
+        assert_eq!(result, 34);
+    }
+
+    #[test]
+    fn get_grouped_antennas_works() {
+        let input = load_input::<Day08>(8, "example").expect("failed to get input");
+        let result = input.get_grouped_antennas();
+
+        assert_eq!(
+            result
+                .get(&GridObject::Antenna('0'))
+                .expect("0 should be in map")
+                .as_slice(),
+            &[
+                Point { x: 8, y: 1 },
+                Point { x: 5, y: 2 },
+                Point { x: 7, y: 3 },
+                Point { x: 4, y: 4 },
+            ]
+        );
+
+        assert_eq!(
+            result
+                .get(&GridObject::Antenna('A'))
+                .expect("0 should be in map")
+                .as_slice(),
+            &[
+                Point { x: 6, y: 5 },
+                Point { x: 8, y: 8 },
+                Point { x: 9, y: 9 },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_antinodes_for_antennas_works() {
+        let input = load_input::<Day08>(8, "example").expect("failed to get input");
+        let result =
+            input.get_antinodes_for_antennas(&Point { x: 4, y: 3 }, &Point { x: 5, y: 5 }, false);
+        assert_eq!(
+            result.as_slice(),
+            &[Point { x: 3, y: 1 }, Point { x: 6, y: 7 }]
+        );
+
+        let result_oob =
+            input.get_antinodes_for_antennas(&Point { x: 4, y: 3 }, &Point { x: 8, y: 4 }, false);
+        assert_eq!(result_oob.as_slice(), &[Point { x: 0, y: 2 }]);
+    }
+}
@@ -0,0 +1,362 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = DiskMap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(disk_map: &Self::Input) -> u64 {
+        let squished = disk_map.squish();
+        squished.checksum()
+    }
+
+    fn part2(disk_map: &Self::Input) -> u64 {
+        let defragged = disk_map.defrag();
+        defragged.checksum()
+    }
+}
+
+#[derive(Clone)]
+pub struct DiskMap {
+    content: Vec<DiskContent>,
+}
+
+impl DiskMap {
+    // this fn is super wonky and could probably be improved to work similar to defrag, but meh I'm tired
+    fn squish(&self) -> DiskMap {
+        let mut new_content: Vec<DiskContent> = Vec::new();
+
+        let mut full_pushed: HashSet<u64> = HashSet::new();
+        let mut partial_pushed: HashSet<u64> = HashSet::new();
+
+        let mut stack_of_files: Vec<DiskContent> = self
+            .content
+            .iter()
+            .filter(|item| matches!(item, DiskContent::File { id: _, size: _ }))
+            .copied()
+            .collect();
+
+        self.content.iter().for_each(|item| {
+            match item {
+                DiskContent::File { id, size: _ } => {
+                    if !partial_pushed.contains(id) {
+                        new_content.push(*item);
+                        full_pushed.insert(*id);
+                    } else if stack_of_files.last().is_some_and(|last| last.id() == *id) {
+                        // only part of this one got moved, the rest stays where it was
+                        new_content.extend(stack_of_files.pop());
+                        full_pushed.insert(*id);
+                    }
+                }
+                DiskContent::FreeSpace { size } => {
+                    let mut free_space = *size;
+
+                    while free_space > 0 {
+                        if let Some(last_file) = stack_of_files.pop() {
+                            if full_pushed.contains(&last_file.id()) {
+                                continue;
+                            }
+                            partial_pushed.insert(last_file.id());
+                            if last_file.size() > free_space {
+                                new_content.push(DiskContent::File {
+                                    id: last_file.id(),
+                                    size: free_space,
+                                });
+                                stack_of_files.push(DiskContent::File {
+                                    id: last_file.id(),
+                                    size: last_file.size() - free_space,
+                                });
+                                free_space = 0;
+                            } else {
+                                new_content.push(last_file);
+                                free_space -= last_file.size();
+                            }
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            };
+        });
+
+        DiskMap {
+            content: new_content,
+        }
+    }
+
+    fn defrag(&self) -> DiskMap {
+        let mut defragged = self.clone();
+
+        let mut i = (defragged.content.len() - 1) as i64;
+
+        while i > 0 {
+            let item = defragged.content[i as usize];
+            match item {
+                DiskContent::File { id: _, size } => {
+                    if let Some((empty_idx, free_space)) =
+                        defragged.find_first_empty_space_with_enough_room(size, i as usize)
+                    {
+                        defragged.content[empty_idx] = item;
+                        defragged.content[i as usize] = DiskContent::FreeSpace { size };
+                        if free_space.size() > size {
+                            defragged.content.insert(
+                                empty_idx + 1,
+                                DiskContent::FreeSpace {
+                                    size: free_space.size() - size,
+                                },
+                            );
+                            i += 1;
+                        }
+                    }
+                }
+                DiskContent::FreeSpace { size: _ } => {}
+            }
+
+            i -= 1;
+        }
+
+        defragged
+    }
+
+    fn checksum(&self) -> u64 {
+        let mut i = 0;
+        self.content.iter().fold(0, |acc, content| {
+            let result = acc + content.checksum(i);
+            i += content.size() as usize;
+            result
+        })
+    }
+
+    fn find_first_empty_space_with_enough_room(
+        &self,
+        size: u64,
+        before: usize,
+    ) -> Option<(usize, DiskContent)> {
+        self.content[..before]
+            .iter()
+            .enumerate()
+            .find(|(_, item)| {
+                matches!(item, DiskContent::FreeSpace { size: _ }) && item.size() >= size
+            })
+            .map(|(i, item)| (i, *item))
+    }
+}
+
+impl FromStr for DiskMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let content = trimmed
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = c.to_digit(10).ok_or_else(|| {
+                    ParseError::at(value, &trimmed[i..], ParseErrorKind::UnexpectedChar(c))
+                })? as u64;
+                if i % 2 == 0 {
+                    Ok(DiskContent::File {
+                        id: (i / 2) as u64,
+                        size: digit,
+                    })
+                } else {
+                    Ok(DiskContent::FreeSpace { size: digit })
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { content })
+    }
+}
+
+impl Display for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = self.content.iter().map(|item| item.to_string()).collect();
+        f.write_str(&s)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum DiskContent {
+    File { id: u64, size: u64 },
+    FreeSpace { size: u64 },
+}
+
+impl DiskContent {
+    fn checksum(&self, start_pos: usize) -> u64 {
+        match self {
+            DiskContent::File { id, size } => (start_pos..start_pos + *size as usize)
+                .map(|i| id * i as u64)
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            DiskContent::File { id: _, size } => *size,
+            DiskContent::FreeSpace { size } => *size,
+        }
+    }
+
+    fn id(&self) -> u64 {
+        match self {
+            DiskContent::File { id, size: _ } => *id,
+            DiskContent::FreeSpace { size: _ } => panic!("can't call id() on free space"),
+        }
+    }
+}
+
+impl Display for DiskContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s: String = match self {
+            DiskContent::File { id, size } => (0..*size).map(|_| id.to_string()).collect(),
+            DiskContent::FreeSpace { size } => (0..*size).map(|_| ".").collect(),
+        };
+
+        f.write_str(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input};
+
+    use super::*;
+
+    /// The disk a block at a time, `None` for free blocks.
+    fn blocks(input: &str) -> Vec<Option<u64>> {
+        input
+            .chars()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                let id = (i % 2 == 0).then_some(i as u64 / 2);
+                std::iter::repeat_n(id, c.to_digit(10).unwrap() as usize)
+            })
+            .collect()
+    }
+
+    fn block_checksum(blocks: &[Option<u64>]) -> u64 {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(i, id)| id.map_or(0, |id| i as u64 * id))
+            .sum()
+    }
+
+    /// Moves one block at a time from the end into the first free block.
+    fn brute_force_squish(input: &str) -> u64 {
+        let mut blocks = blocks(input);
+        while let Some(free) = blocks.iter().position(Option::is_none) {
+            match blocks.iter().rposition(Option::is_some) {
+                Some(last) if last > free => blocks.swap(free, last),
+                _ => break,
+            }
+        }
+        block_checksum(&blocks)
+    }
+
+    /// Moves each whole file once, highest id first, into the leftmost gap it fits in.
+    fn brute_force_defrag(input: &str) -> u64 {
+        let mut blocks = blocks(input);
+        let highest = (input.len() as u64 - 1) / 2;
+
+        for id in (0..=highest).rev() {
+            let start = blocks.iter().position(|b| *b == Some(id)).unwrap();
+            let size = blocks.iter().filter(|b| **b == Some(id)).count();
+
+            let gap = (0..start).find(|&i| blocks[i..i + size].iter().all(Option::is_none));
+            if let Some(gap) = gap {
+                for i in 0..size {
+                    blocks.swap(gap + i, start + i);
+                }
+            }
+        }
+        block_checksum(&blocks)
+    }
+
+    #[test]
+    fn checksums_match_brute_force() {
+        generate::check(300, |rng| {
+            let input = generate::disk_map(rng);
+            let disk_map = parse_input::<Day09>(&input).unwrap();
+            assert_eq!(
+                Day09::part1(&disk_map),
+                brute_force_squish(&input),
+                "{input}"
+            );
+            assert_eq!(
+                Day09::part2(&disk_map),
+                brute_force_defrag(&input),
+                "{input}"
+            );
+        });
+    }
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day09>(9, "example").expect("failed to read input");
+        let result = Day09::part1(&input);
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = load_input::<Day09>(9, "example").expect("failed to read input");
+        let result = Day09::part2(&input);
+        assert_eq!(result, 2858);
+    }
+
+    #[test]
+    fn squish_keeps_the_rest_of_a_file_it_split() {
+        let disk_map = "74829".parse::<DiskMap>().unwrap();
+        assert_eq!(disk_map.squish().to_string(), "000000022221111111122222");
+        assert_eq!(Day09::part1(&disk_map), 394);
+    }
+
+    #[test]
+    fn diskmap_from_str_works() {
+        let result = "12345".parse::<DiskMap>().unwrap();
+        assert_eq!(
+            result.content.as_slice(),
+            &[
+                DiskContent::File { id: 0, size: 1 },
+                DiskContent::FreeSpace { size: 2 },
+                DiskContent::File { id: 1, size: 3 },
+                DiskContent::FreeSpace { size: 4 },
+                DiskContent::File { id: 2, size: 5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn diskmap_to_str_works() {
+        let result = "2333133121414131402".parse::<DiskMap>().unwrap();
+        assert_eq!(
+            result.to_string(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+    }
+
+    #[test]
+    fn diskmap_from_str_points_at_bad_char() {
+        let result = "123x5".parse::<DiskMap>();
+        assert_eq!(
+            result.err(),
+            Some(ParseError::new(1, 4, ParseErrorKind::UnexpectedChar('x')))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Grid, Point};
+use std::str::FromStr;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = TrailMap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        let trailheads = map.find_trailheads();
+        trailheads
+            .iter()
+            .fold(0, |acc, trailhead| acc + map.get_trailhead_score(trailhead))
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        let trailheads = map.find_trailheads();
+        trailheads.iter().fold(0, |acc, trailhead| {
+            acc + map.get_trailhead_rating(trailhead)
+        })
+    }
+}
+
+pub struct TrailMap {
+    map: Grid<u8>,
+}
+
+impl TrailMap {
+    fn get_trailhead_score(&self, trailhead: &Point) -> u64 {
+        self.map
+            .flood_fill(*trailhead, |from, to| *to == from + 1)
+            .iter()
+            .filter(|pos| self.get_val_at_pos(pos) == 9)
+            .count() as u64
+    }
+
+    fn get_trailhead_rating(&self, trailhead: &Point) -> u64 {
+        let mut paths = Vec::new();
+        self.traverse_to_9s(trailhead, vec![*trailhead], &mut paths);
+
+        paths.len() as u64
+    }
+
+    fn traverse_to_9s(
+        &self,
+        cur_pos: &Point,
+        cur_path: Vec<Point>,
+        all_paths: &mut Vec<Vec<Point>>,
+    ) {
+        let cur_val = self.get_val_at_pos(cur_pos);
+
+        if cur_val == 9 {
+            all_paths.push(cur_path);
+            return;
+        }
+
+        self.map.neighbors(*cur_pos).for_each(|(_, new_pos)| {
+            if self.get_val_at_pos(&new_pos) == cur_val + 1 {
+                let mut new_path = cur_path.clone();
+                new_path.push(new_pos);
+                self.traverse_to_9s(&new_pos, new_path, all_paths);
+            }
+        });
+    }
+
+    fn find_trailheads(&self) -> Vec<Point> {
+        self.map
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    fn get_val_at_pos(&self, pos: &Point) -> u8 {
+        self.map[*pos]
+    }
+}
+
+impl FromStr for TrailMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(value, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or(ParseErrorKind::UnexpectedChar(c))
+        })?;
+        Ok(Self { map })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let map = load_input::<Day10>(10, "example").expect("failed to read input");
+        let result = Day10::part1(&map);
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = load_input::<Day10>(10, "example").expect("failed to read input");
+        let result = Day10::part2(&map);
+        assert_eq!(result, 81);
+    }
+
+    #[test]
+    fn find_trailheads_works() {
+        let map: TrailMap = "0123\n\
+                              1234\n\
+                              8065\n\
+                              9876"
+            .parse()
+            .unwrap();
+        let result = map.find_trailheads();
+        assert_eq!(&result, &[Point { x: 0, y: 0 }, Point { x: 1, y: 2 }]);
+    }
+}
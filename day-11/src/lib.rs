@@ -0,0 +1,143 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::WithParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use serde::Deserialize;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = WithParams<Stones, Day11Params>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = Day11Params;
+
+    fn parse(input: &str, params: &Day11Params) -> Result<Self::Input, anyhow::Error> {
+        Ok(WithParams {
+            input: input.parse()?,
+            params: *params,
+        })
+    }
+
+    fn part1(stones: &Self::Input) -> u64 {
+        blink_stones_n_times(&stones.input, stones.params.part1_blinks)
+    }
+
+    fn part2(stones: &Self::Input) -> u64 {
+        blink_stones_n_times(&stones.input, stones.params.part2_blinks)
+    }
+}
+
+/// How many times to blink in each part.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day11Params {
+    /// Blinks for part 1.
+    pub part1_blinks: u64,
+    /// Blinks for part 2.
+    pub part2_blinks: u64,
+}
+
+impl Default for Day11Params {
+    fn default() -> Self {
+        Self {
+            part1_blinks: 25,
+            part2_blinks: 75,
+        }
+    }
+}
+
+fn blink_stones_n_times(stones: &Stones, n: u64) -> u64 {
+    let mut cache = HashMap::new();
+
+    stones.stones.iter().fold(0, |acc, stone| {
+        acc + blink_on_stone_n_times_with_cache(stone, n, &mut cache)
+    })
+}
+
+#[derive(Clone)]
+pub struct Stones {
+    stones: Vec<String>,
+}
+
+impl FromStr for Stones {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let stones = value
+            .split_whitespace()
+            .map(|stone| {
+                if stone.chars().all(|c| c.is_ascii_digit()) {
+                    Ok(stone.to_string())
+                } else {
+                    Err(ParseError::at(
+                        value,
+                        stone,
+                        ParseErrorKind::InvalidNumber(stone.to_string()),
+                    ))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { stones })
+    }
+}
+
+fn blink_on_stone(stone: &str) -> Vec<String> {
+    if stone == "0" {
+        vec!["1".into()]
+    } else if stone.len().is_multiple_of(2) {
+        let (left, right) = stone.split_at(stone.len() / 2);
+        let trimmed_right = right.trim_start_matches("0").to_string();
+        vec![
+            left.into(),
+            if !trimmed_right.is_empty() {
+                trimmed_right
+            } else {
+                "0".into()
+            },
+        ]
+    } else {
+        let new_stone_num = stone.parse::<u64>().expect("all stones must be numbers") * 2024;
+        vec![new_stone_num.to_string()]
+    }
+}
+
+fn blink_on_stone_n_times_with_cache(
+    stone: &str,
+    n: u64,
+    cache: &mut HashMap<(String, u64), u64>,
+) -> u64 {
+    if n == 0 {
+        return 1;
+    }
+
+    if let Some(num_stones) = cache.get(&(stone.to_string(), n)) {
+        return *num_stones;
+    }
+
+    let new_stones = blink_on_stone(stone);
+
+    let num = new_stones.iter().fold(0, |acc, stone| {
+        acc + blink_on_stone_n_times_with_cache(stone, n - 1, cache)
+    });
+
+    cache.insert((stone.to_string(), n), num);
+    num
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let stones = load_input::<Day11>(11, "example").expect("failed to read input");
+        let result = Day11::part1(&stones);
+        assert_eq!(result, 55312);
+    }
+}
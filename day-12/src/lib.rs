@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Grid, Region};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = GardenMap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        let area_and_perims = map.get_area_and_perimeters();
+        area_and_perims
+            .iter()
+            .fold(0, |acc, (_, (area, perim))| acc + area * perim)
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        let area_and_sides = map.get_area_and_sides();
+        area_and_sides
+            .iter()
+            .fold(0, |acc, (_, (area, sides))| acc + area * sides)
+    }
+}
+
+pub struct GardenMap {
+    map: Grid<char>,
+}
+
+impl GardenMap {
+    fn get_area_and_perimeters(&self) -> Vec<(char, (u64, u64))> {
+        self.find_all_regions()
+            .iter()
+            .map(|(c, region)| (*c, (region.area() as u64, region.perimeter() as u64)))
+            .collect()
+    }
+
+    fn get_area_and_sides(&self) -> Vec<(char, (u64, u64))> {
+        self.find_all_regions()
+            .iter()
+            .map(|(c, region)| (*c, (region.area() as u64, region.sides() as u64)))
+            .collect()
+    }
+
+    fn find_all_regions(&self) -> Vec<(char, Region)> {
+        self.map
+            .label_regions(|a, b| a == b)
+            .iter()
+            .map(|cells| (self.map[cells[0]], Region::new(cells.iter().copied())))
+            .collect()
+    }
+}
+
+impl FromStr for GardenMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(value, |c| {
+            if c.is_ascii_uppercase() {
+                Ok(c)
+            } else {
+                Err(ParseErrorKind::UnexpectedChar(c))
+            }
+        })?;
+
+        Ok(Self { map })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input};
+
+    use super::*;
+
+    /// Prices for every region, found by flood filling a plain 2d array and counting fences
+    /// one at a time. A fence starts a new side unless the cell before it along the fence has
+    /// the same one.
+    fn brute_force_prices(input: &str) -> (u64, u64) {
+        let garden: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+        let (height, width) = (garden.len() as i64, garden[0].len() as i64);
+        let plant = |x: i64, y: i64| {
+            (x >= 0 && y >= 0 && x < width && y < height).then(|| garden[y as usize][x as usize])
+        };
+        let fences = [(0, -1, -1, 0), (0, 1, -1, 0), (-1, 0, 0, -1), (1, 0, 0, -1)];
+
+        let mut seen = vec![vec![false; width as usize]; height as usize];
+        let (mut by_perimeter, mut by_sides) = (0, 0);
+        for (y, x) in (0..height).flat_map(|y| (0..width).map(move |x| (y, x))) {
+            if seen[y as usize][x as usize] {
+                continue;
+            }
+            let kind = plant(x, y);
+            seen[y as usize][x as usize] = true;
+            let mut stack = vec![(x, y)];
+            let (mut area, mut perimeter, mut sides) = (0, 0, 0);
+
+            while let Some((x, y)) = stack.pop() {
+                area += 1;
+                for (dx, dy, back_x, back_y) in fences {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if plant(nx, ny) == kind {
+                        if !seen[ny as usize][nx as usize] {
+                            seen[ny as usize][nx as usize] = true;
+                            stack.push((nx, ny));
+                        }
+                        continue;
+                    }
+
+                    perimeter += 1;
+                    let (bx, by) = (x + back_x, y + back_y);
+                    if plant(bx, by) != kind || plant(bx + dx, by + dy) == kind {
+                        sides += 1;
+                    }
+                }
+            }
+
+            by_perimeter += area * perimeter;
+            by_sides += area * sides;
+        }
+
+        (by_perimeter, by_sides)
+    }
+
+    #[test]
+    fn prices_match_brute_force() {
+        generate::check(200, |rng| {
+            let input = generate::garden_map(rng);
+            let map = parse_input::<Day12>(&input).unwrap();
+            assert_eq!(
+                (Day12::part1(&map), Day12::part2(&map)),
+                brute_force_prices(&input),
+                "{input}"
+            );
+        });
+    }
+
+    #[test]
+    fn part1_works() {
+        let map = load_input::<Day12>(12, "example").expect("failed to read input");
+        let result = Day12::part1(&map);
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = load_input::<Day12>(12, "example").expect("failed to read input");
+        let result = Day12::part2(&map);
+        assert_eq!(result, 1206);
+    }
+
+    #[test]
+    fn get_area_and_perimeter_works() {
+        let map = load_input::<Day12>(12, "example").expect("failed to read input");
+        let result = map.get_area_and_perimeters();
+        assert_eq!(
+            &result,
+            &[
+                ('R', (12, 18)),
+                ('I', (4, 8)),
+                ('C', (14, 28)),
+                ('F', (10, 18)),
+                ('V', (13, 20)),
+                ('J', (11, 20)),
+                ('C', (1, 4)),
+                ('E', (13, 18)),
+                ('I', (14, 22)),
+                ('M', (5, 12)),
+                ('S', (3, 8))
+            ]
+        );
+    }
+
+    #[test]
+    fn get_area_and_sides_works() {
+        let map = load_input::<Day12>(12, "example").expect("failed to read input");
+        let result = map.get_area_and_sides();
+        assert_eq!(
+            &result,
+            &[
+                ('R', (12, 10)),
+                ('I', (4, 4)),
+                ('C', (14, 22)),
+                ('F', (10, 12)),
+                ('V', (13, 10)),
+                ('J', (11, 12)),
+                ('C', (1, 4)),
+                ('E', (13, 8)),
+                ('I', (14, 16)),
+                ('M', (5, 6)),
+                ('S', (3, 6))
+            ]
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use std::str::FromStr;
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(parse::blocks(input)?)
+    }
+
+    fn part1(machines: &Self::Input) -> u64 {
+        machines
+            .iter()
+            .filter_map(|machine| machine.least_tokens_for_prize(100))
+            .sum()
+    }
+
+    fn part2(machines: &Self::Input) -> u64 {
+        machines
+            .iter()
+            .map(|machine| {
+                let new_loc = Location {
+                    x: machine.prize.x + 10000000000000,
+                    y: machine.prize.y + 10000000000000,
+                };
+
+                ClawMachine {
+                    buttons: machine.buttons.clone(),
+                    prize: new_loc,
+                }
+            })
+            .filter_map(|machine| machine.least_tokens_for_prize(u64::MAX))
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ClawMachine {
+    buttons: [Button; 2],
+    prize: Location,
+}
+
+impl ClawMachine {
+    fn least_tokens_for_prize(&self, max_btn_presses: u64) -> Option<u64> {
+        self.find_btn_combos_get_prize(max_btn_presses)
+            .map(|combo| self.combo_token_amt(&combo))
+    }
+
+    fn find_btn_combos_get_prize(&self, max_btn_presses: u64) -> Option<(u64, u64)> {
+        let cx = self.prize.x as i64;
+        let cy = self.prize.y as i64;
+        let ax = self.buttons[0].x_translation as i64;
+        let ay = self.buttons[0].y_translation as i64;
+        let bx = self.buttons[1].x_translation as i64;
+        let by = self.buttons[1].y_translation as i64;
+
+        let denom = (bx * ay) - (by * ax);
+
+        if denom == 0 {
+            return self.find_btn_combos_along_line(max_btn_presses);
+        }
+
+        if ((cy * bx) - (by * cx)) % denom != 0 {
+            return None;
+        }
+        let a = ((cy * bx) - (by * cx)) / denom;
+
+        if ((ay * cx) - (ax * cy)) % denom != 0 {
+            return None;
+        }
+        let b = ((ay * cx) - (ax * cy)) / denom;
+
+        if a < 0 || b < 0 || a as u64 > max_btn_presses || b as u64 > max_btn_presses {
+            return None;
+        }
+
+        Some((a as u64, b as u64))
+    }
+
+    /// When both buttons move the claw the same way there's no single answer to solve for, every
+    /// press just moves it further along the same line. So it's one equation along an axis the
+    /// buttons actually move in, and since the cost changes by the same amount every time presses
+    /// of A are swapped for B, the cheapest is either as few presses of A as possible or as many.
+    fn find_btn_combos_along_line(&self, max_btn_presses: u64) -> Option<(u64, u64)> {
+        let [a_btn, b_btn] = &self.buttons;
+        let (p, q, target) = if a_btn.x_translation != 0 || b_btn.x_translation != 0 {
+            (a_btn.x_translation, b_btn.x_translation, self.prize.x)
+        } else {
+            (a_btn.y_translation, b_btn.y_translation, self.prize.y)
+        };
+
+        let combo = match (p, q) {
+            (0, 0) => Some((0, 0)),
+            // a button that doesn't move the claw is never worth pressing
+            (0, q) => (target % q == 0).then(|| (0, target / q)),
+            (p, 0) => (target % p == 0).then(|| (target / p, 0)),
+            (p, q) => {
+                // presses of A that leave a multiple of q repeat every `step`
+                let step = q / gcd(p, q);
+                let first = (0..step).find(|a| a * p <= target && (target - a * p) % q == 0)?;
+
+                let fewest = target
+                    .saturating_sub(max_btn_presses.saturating_mul(q))
+                    .div_ceil(p);
+                let most = max_btn_presses.min(target / p);
+                if most < first {
+                    return None;
+                }
+                let fewest = first + fewest.saturating_sub(first).div_ceil(step) * step;
+                let most = most - (most - first) % step;
+                if fewest > most {
+                    return None;
+                }
+
+                [fewest, most]
+                    .map(|a| (a, (target - a * p) / q))
+                    .into_iter()
+                    .min_by_key(|combo| self.combo_token_amt(combo))
+            }
+        };
+
+        let reaches = |a: u64, b: u64, a_move: u64, b_move: u64, prize: u64| {
+            let a_moved = a.checked_mul(a_move);
+            let b_moved = b.checked_mul(b_move);
+            a_moved.zip(b_moved).and_then(|(a, b)| a.checked_add(b)) == Some(prize)
+        };
+        combo.filter(|&(a, b)| {
+            a <= max_btn_presses
+                && b <= max_btn_presses
+                && reaches(a, b, a_btn.x_translation, b_btn.x_translation, self.prize.x)
+                && reaches(a, b, a_btn.y_translation, b_btn.y_translation, self.prize.y)
+        })
+    }
+
+    fn combo_token_amt(&self, combo: &(u64, u64)) -> u64 {
+        combo.0 * self.buttons[0].cost + combo.1 * self.buttons[1].cost
+    }
+}
+
+impl FromStr for ClawMachine {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lines = value.lines();
+        let mut next_line = |expected| {
+            lines
+                .next()
+                .ok_or_else(|| ParseError::end_of(value, value, expected))
+        };
+
+        let a_button = Button::parse(value, next_line("button A")?)?;
+        let b_button = Button::parse(value, next_line("button B")?)?;
+        let prize_loc = Location::parse(value, next_line("a prize")?)?;
+
+        Ok(Self {
+            buttons: [a_button, b_button],
+            prize: prize_loc,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Button {
+    x_translation: u64,
+    y_translation: u64,
+    cost: u64,
+}
+
+impl Button {
+    /// Parses a button from `line`, which is a slice of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let cost = if line.starts_with("Button A") { 3 } else { 1 };
+
+        let (_, translations) = parse::split_once(input, line, ":")?;
+        let (x_str, y_str) = parse::split_once(input, translations, ",")?;
+        let x = parse_num_after_sign(input, x_str, "+")?;
+        let y = parse_num_after_sign(input, y_str, "+")?;
+
+        Ok(Self {
+            x_translation: x,
+            y_translation: y,
+            cost,
+        })
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn parse_num_after_sign(input: &str, value: &str, sign: &str) -> Result<u64, ParseError> {
+    let (_, num_str) = parse::split_once(input, value.trim(), sign)?;
+    parse::num(input, num_str)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Location {
+    x: u64,
+    y: u64,
+}
+
+impl Location {
+    /// Parses the prize's location from `line`, which is a slice of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (x_str, y_str) = parse::split_once(input, line, ",")?;
+        let x = parse_num_after_sign(input, x_str, "=")?;
+        let y = parse_num_after_sign(input, y_str, "=")?;
+
+        Ok(Self { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{load_input, parse_input};
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let machines = load_input::<Day13>(13, "example").expect("failed to read input");
+        assert_eq!(Day13::part1(&machines), 480);
+    }
+
+    #[test]
+    fn least_tokens_for_prize_works() {
+        let machine = ClawMachine {
+            buttons: [
+                Button {
+                    x_translation: 94,
+                    y_translation: 34,
+                    cost: 3,
+                },
+                Button {
+                    x_translation: 22,
+                    y_translation: 67,
+                    cost: 1,
+                },
+            ],
+            prize: Location { x: 8400, y: 5400 },
+        };
+
+        assert_eq!(machine.least_tokens_for_prize(100), Some(280));
+    }
+
+    #[test]
+    fn buttons_in_the_same_direction_use_the_cheapest_mix() {
+        let machine = |a: u64, a_cost: u64| ClawMachine {
+            buttons: [
+                Button {
+                    x_translation: a,
+                    y_translation: 2 * a,
+                    cost: a_cost,
+                },
+                Button {
+                    x_translation: 1,
+                    y_translation: 2,
+                    cost: 1,
+                },
+            ],
+            prize: Location { x: 10, y: 20 },
+        };
+
+        // B is cheaper for the distance, unless A goes more than 3 times as far
+        assert_eq!(machine(2, 3).find_btn_combos_get_prize(100), Some((0, 10)));
+        assert_eq!(machine(4, 3).find_btn_combos_get_prize(100), Some((2, 2)));
+        assert_eq!(machine(4, 3).find_btn_combos_get_prize(1), None);
+        assert_eq!(machine(3, 3).find_btn_combos_get_prize(5), Some((2, 4)));
+
+        // or A costs less than twice as much as B when it goes twice as far
+        assert_eq!(machine(2, 1).find_btn_combos_get_prize(100), Some((5, 0)));
+        assert_eq!(machine(2, 3).least_tokens_for_prize(100), Some(10));
+    }
+
+    #[test]
+    fn buttons_that_only_move_along_one_axis() {
+        let machines =
+            parse_input::<Day13>("Button A: X+5, Y+3\nButton B: X+0, Y+7\nPrize: X=10, Y=20")
+                .expect("failed to parse input");
+        assert_eq!(machines[0].find_btn_combos_get_prize(100), Some((2, 2)));
+
+        let machines =
+            parse_input::<Day13>("Button A: X+0, Y+3\nButton B: X+4, Y+0\nPrize: X=10, Y=9")
+                .expect("failed to parse input");
+        assert_eq!(machines[0].find_btn_combos_get_prize(100), None);
+    }
+
+    #[test]
+    fn can_read_input() {
+        let machines = load_input::<Day13>(13, "example").expect("failed to read input");
+        assert_eq!(
+            machines[0],
+            ClawMachine {
+                buttons: [
+                    Button {
+                        x_translation: 94,
+                        y_translation: 34,
+                        cost: 3
+                    },
+                    Button {
+                        x_translation: 22,
+                        y_translation: 67,
+                        cost: 1
+                    },
+                ],
+                prize: Location { x: 8400, y: 5400 },
+            }
+        );
+
+        assert_eq!(
+            machines[3],
+            ClawMachine {
+                buttons: [
+                    Button {
+                        x_translation: 69,
+                        y_translation: 23,
+                        cost: 3
+                    },
+                    Button {
+                        x_translation: 27,
+                        y_translation: 71,
+                        cost: 1
+                    },
+                ],
+                prize: Location { x: 18641, y: 10279 },
+            }
+        );
+    }
+
+    #[test]
+    fn claw_machine_parse_works() {
+        let machine: ClawMachine = "\
+            Button A: X+94, Y+34\n\
+            Button B: X+22, Y+67\n\
+            Prize: X=8400, Y=5400"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            machine,
+            ClawMachine {
+                buttons: [
+                    Button {
+                        x_translation: 94,
+                        y_translation: 34,
+                        cost: 3
+                    },
+                    Button {
+                        x_translation: 22,
+                        y_translation: 67,
+                        cost: 1
+                    },
+                ],
+                prize: Location { x: 8400, y: 5400 },
+            }
+        )
+    }
+
+    #[test]
+    fn claw_machine_parse_points_at_bad_num() {
+        let result = "\
+            Button A: X+94, Y+34\n\
+            Button B: X+2z, Y+67\n\
+            Prize: X=8400, Y=5400"
+            .parse::<ClawMachine>();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 13: invalid number \"2z\""
+        );
+    }
+}
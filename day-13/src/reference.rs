@@ -0,0 +1,51 @@
+//! Tries every number of presses of both buttons, so it only works for part 1.
+
+use crate::ClawMachine;
+
+pub fn part1(machines: &[ClawMachine]) -> u64 {
+    machines
+        .iter()
+        .filter_map(|machine| least_tokens_for_prize(machine, 100))
+        .sum()
+}
+
+pub fn least_tokens_for_prize(machine: &ClawMachine, max_btn_presses: u64) -> Option<u64> {
+    let [a, b] = &machine.buttons;
+    (0..=max_btn_presses)
+        .flat_map(|a_presses| (0..=max_btn_presses).map(move |b_presses| (a_presses, b_presses)))
+        .filter(|(a_presses, b_presses)| {
+            a_presses * a.x_translation + b_presses * b.x_translation == machine.prize.x
+                && a_presses * a.y_translation + b_presses * b.y_translation == machine.prize.y
+        })
+        .map(|(a_presses, b_presses)| a_presses * a.cost + b_presses * b.cost)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input, Solution};
+
+    use super::*;
+    use crate::Day13;
+
+    #[test]
+    fn matches_on_the_example() {
+        let machines = load_input::<Day13>(13, "example").expect("failed to read input");
+        assert_eq!(Day13::part1(&machines), part1(&machines));
+    }
+
+    #[test]
+    fn matches_on_generated_machines() {
+        generate::check(100, |rng| {
+            let input = generate::claw_machines(rng);
+            let machines = parse_input::<Day13>(&input).unwrap();
+            for machine in &machines {
+                assert_eq!(
+                    machine.least_tokens_for_prize(100),
+                    least_tokens_for_prize(machine, 100),
+                    "{machine:?}"
+                );
+            }
+        });
+    }
+}
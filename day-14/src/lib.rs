@@ -0,0 +1,240 @@
+use std::{
+    io::{self, IsTerminal},
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::ensure;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use grid::{BitGrid, Color, Direction8, Grid, Image, Point, Rgb, Torus, Vec2};
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = HQ;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = Day14Params;
+
+    fn parse(input: &str, params: &Day14Params) -> Result<Self::Input, anyhow::Error> {
+        HQ::new(input, params.width, params.height)
+    }
+
+    fn part1(hq: &Self::Input) -> u64 {
+        hq.get_safety_number(100)
+    }
+
+    fn part2(hq: &Self::Input) -> u64 {
+        hq.find_christmas_tree()
+    }
+}
+
+pub fn print_christmas_tree(hq: &HQ) {
+    println!(
+        "{}",
+        render_christmas_tree(hq, std::io::stdout().is_terminal())
+    );
+}
+
+/// The bathroom with the robots drawn as `X` once they've made the christmas tree.
+pub fn render_christmas_tree(hq: &HQ, color: bool) -> String {
+    hq.render_robots(&hq.get_all_robots_after_time(Day14::part2(hq)), color)
+}
+
+/// Saves a picture of the robots once they've made the christmas tree, as a PPM or PNG depending
+/// on the path.
+pub fn save_christmas_tree(hq: &HQ, path: impl AsRef<Path>) -> io::Result<()> {
+    hq.image_after_time(Day14::part2(hq)).scale(4).save(path)
+}
+
+/// The size of the bathroom the robots are in, which is smaller for the example.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day14Params {
+    /// How many tiles wide the bathroom is.
+    pub width: usize,
+    /// How many tiles tall the bathroom is.
+    pub height: usize,
+}
+
+impl Default for Day14Params {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+pub struct HQ {
+    area: Torus,
+
+    robots: Vec<Robot>,
+}
+
+impl HQ {
+    fn new(input: &str, x_len: usize, y_len: usize) -> Result<Self, anyhow::Error> {
+        ensure!(
+            x_len > 0 && y_len > 0,
+            "the bathroom can't be {x_len}x{y_len}, it needs at least one tile"
+        );
+
+        Ok(Self {
+            area: Torus::new(x_len, y_len),
+
+            robots: parse::lines(input)?,
+        })
+    }
+
+    fn get_safety_number(&self, seconds: u64) -> u64 {
+        self.area
+            .quadrant_counts(self.get_all_robots_after_time(seconds))
+            .iter()
+            .product::<usize>() as u64
+    }
+
+    fn find_robot_location_after_time(&self, robot: &Robot, seconds: u64) -> Point {
+        self.area.advance(robot.pos, robot.vel, seconds)
+    }
+
+    fn get_all_robots_after_time(&self, seconds: u64) -> Vec<Point> {
+        self.robots
+            .iter()
+            .map(|robot| self.find_robot_location_after_time(robot, seconds))
+            .collect()
+    }
+
+    fn find_christmas_tree(&self) -> u64 {
+        let mut sims: Vec<_> = (0..10_000)
+            .map(|seconds| {
+                let new_robots: Vec<_> = self.get_all_robots_after_time(seconds);
+                let robot_locs = self.occupancy(&new_robots);
+
+                let mut total_neighbors: u64 = 0;
+                for robot in &new_robots {
+                    total_neighbors += self.count_neighbors(robot, &robot_locs);
+                }
+
+                (seconds, total_neighbors)
+            })
+            .collect();
+
+        sims.sort_by_key(|sim| std::cmp::Reverse(sim.1));
+        sims[0].0
+    }
+
+    fn occupancy(&self, robots: &[Point]) -> BitGrid {
+        let mut occupied = BitGrid::new(self.area.width(), self.area.height());
+        robots.iter().for_each(|robot| {
+            occupied.insert(*robot);
+        });
+        occupied
+    }
+
+    fn count_neighbors(&self, robot: &Point, robots: &BitGrid) -> u64 {
+        Direction8::iter()
+            .filter(|dir| Point::try_from(robot.add_direction8(dir)).is_ok_and(|n| robots[n]))
+            .count() as u64
+    }
+
+    fn image_after_time(&self, seconds: u64) -> Image {
+        let robots = self.occupancy(&self.get_all_robots_after_time(seconds));
+        let area = Grid::from_fn(self.area.width(), self.area.height(), |p| robots[p]);
+
+        area.to_image(|robot| {
+            if *robot {
+                Color::Green.into()
+            } else {
+                Rgb::BLACK
+            }
+        })
+    }
+
+    fn render_robots(&self, robots: &[Point], color: bool) -> String {
+        Grid::new(self.area.width(), self.area.height(), '.')
+            .render()
+            .mark(robots.iter().copied(), 'X', Color::Green)
+            .color(color)
+            .to_string()
+    }
+}
+
+struct Robot {
+    pos: Point,
+    vel: Vec2,
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (p, v) = parse::split_once(value, value, " ")?;
+
+        let p = parse::strip_prefix(value, p.trim(), "p=")?;
+        let (px_str, py_str) = parse::split_once(value, p, ",")?;
+        let (px, py): (usize, usize) = (parse::num(value, px_str)?, parse::num(value, py_str)?);
+
+        let v = parse::strip_prefix(value, v.trim(), "v=")?;
+        let (vx_str, vy_str) = parse::split_once(value, v, ",")?;
+        let (vx, vy): (i64, i64) = (parse::num(value, vx_str)?, parse::num(value, vy_str)?);
+
+        Ok(Self {
+            pos: Point { x: px, y: py },
+            vel: Vec2::new(vx, vy),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let hq = load_input::<Day14>(14, "example").expect("failed to read input");
+        assert_eq!(Day14::part1(&hq), 12);
+    }
+
+    #[test]
+    fn christmas_tree_is_saved_at_4_pixels_a_tile() {
+        let hq = load_input::<Day14>(14, "example").expect("failed to read input");
+        let path = std::env::temp_dir().join(format!("day-14-tree-{}.ppm", std::process::id()));
+
+        save_christmas_tree(&hq, &path).expect("failed to save the tree");
+
+        let image = std::fs::read(&path).expect("failed to read the tree");
+        assert!(image.starts_with(b"P6\n44 28\n255\n"));
+        assert_eq!(image.len(), "P6\n44 28\n255\n".len() + 44 * 28 * 3);
+        std::fs::remove_file(path).expect("failed to clean up the tree");
+    }
+
+    #[test]
+    fn robots_wrap_around_the_edges() {
+        let params = Day14Params {
+            width: 11,
+            height: 7,
+        };
+        let hq = Day14::parse("p=2,4 v=2,-3", &params).expect("failed to parse input");
+        assert_eq!(hq.get_all_robots_after_time(5), [Point { x: 1, y: 3 }]);
+    }
+
+    #[test]
+    fn an_empty_bathroom_is_an_error() {
+        let params = Day14Params {
+            width: 0,
+            height: 7,
+        };
+        let err = Day14::parse("p=2,4 v=2,-3", &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "the bathroom can't be 0x7, it needs at least one tile"
+        );
+    }
+}
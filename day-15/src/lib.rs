@@ -0,0 +1,422 @@
+use std::{fmt, io, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Color, Direction, Frames, Grid, Image, Point, Rgb};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = RobotMap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        let mut map = map.clone();
+        map.move_the_robot_all_steps();
+        map.get_sum_of_box_gps_coords()
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        let mut doubled_map = map.double_width();
+        doubled_map.move_the_robot_all_steps();
+        doubled_map.get_sum_of_box_gps_coords()
+    }
+}
+
+#[derive(Clone)]
+pub struct RobotMap {
+    map: Grid<Object>,
+    cur_robot_loc: Point,
+    robot_directions: Vec<Direction>,
+}
+
+impl RobotMap {
+    /// The map for part 2, where everything but the robot is twice as wide.
+    pub fn double_width(&self) -> Self {
+        let new_rows: Vec<Vec<_>> = self
+            .map
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|obj| match *obj {
+                        Object::Wall => [Object::Wall, Object::Wall],
+                        Object::Box => [Object::LeftBox, Object::RightBox],
+                        Object::Robot => [Object::Robot, Object::Empty],
+                        Object::Empty => [Object::Empty, Object::Empty],
+                        _ => panic!("cannot double this object"),
+                    })
+                    .collect()
+            })
+            .collect();
+        let new_map = Grid::from_rows(new_rows).expect("rows should all be the same width");
+
+        let robot_loc = new_map
+            .position(|obj| *obj == Object::Robot)
+            .expect("robot should still be there after doubling");
+
+        Self {
+            map: new_map,
+            cur_robot_loc: robot_loc,
+            robot_directions: self.robot_directions.clone(),
+        }
+    }
+
+    fn get_sum_of_box_gps_coords(&self) -> u64 {
+        let boxes = self.get_box_locs();
+
+        boxes
+            .iter()
+            .fold(0, |acc, pos| acc + pos.y as u64 * 100 + pos.x as u64)
+    }
+
+    fn get_box_locs(&self) -> Vec<Point> {
+        self.map
+            .iter()
+            .filter(|(_, obj)| **obj == Object::Box || **obj == Object::LeftBox)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Writes a frame of the warehouse before the robot moves and after each of its moves.
+    pub fn record_moves(&self, frames: &mut Frames) -> io::Result<()> {
+        let mut map = self.clone();
+        frames.push(&map.to_image())?;
+
+        for dir in self.robot_directions.iter() {
+            map.move_robot(dir);
+            frames.push(&map.to_image())?;
+        }
+
+        Ok(())
+    }
+
+    fn to_image(&self) -> Image {
+        self.map.to_image(|obj| match obj {
+            Object::Wall => Rgb::GRAY,
+            Object::Box | Object::LeftBox | Object::RightBox => Color::Yellow.into(),
+            Object::Robot => Color::Red.into(),
+            Object::Empty => Rgb::BLACK,
+        })
+    }
+
+    fn move_the_robot_all_steps(&mut self) {
+        let robot_dirs = self.robot_directions.clone();
+        for dir in robot_dirs.iter() {
+            self.move_robot(dir);
+        }
+    }
+
+    fn move_robot(&mut self, dir: &Direction) {
+        let next_pos = self.step(&self.cur_robot_loc, dir);
+
+        match self.get_obj_at_loc(&next_pos) {
+            Object::Empty => {
+                self.move_robot_with_empty_space_behind(&next_pos);
+            }
+            Object::Wall => {}
+            Object::Box => {
+                self.find_empty_space_and_move_objs_if_possible(&next_pos, dir);
+            }
+            Object::Robot => panic!("the robot should never be able to walk into itself"),
+            Object::LeftBox => match dir {
+                Direction::Up | Direction::Down => {
+                    self.move_double_width_boxes_and_robot_vertically_if_possible(&next_pos, dir);
+                }
+                Direction::Right => {
+                    self.find_empty_space_and_move_objs_if_possible(&next_pos, dir);
+                }
+                Direction::Left => {
+                    panic!("can't hit a LeftBox from the left");
+                }
+            },
+            Object::RightBox => match dir {
+                Direction::Up | Direction::Down => {
+                    self.move_double_width_boxes_and_robot_vertically_if_possible(&next_pos, dir);
+                }
+                Direction::Left => {
+                    self.find_empty_space_and_move_objs_if_possible(&next_pos, dir);
+                }
+                Direction::Right => {
+                    panic!("can't hit a RightBox from the right");
+                }
+            },
+        }
+    }
+
+    fn move_robot_with_empty_space_behind(&mut self, next_pos: &Point) {
+        let cur_robot_loc = self.cur_robot_loc;
+        self.set_obj_at_loc(&cur_robot_loc, Object::Empty);
+        self.set_obj_at_loc(next_pos, Object::Robot);
+    }
+
+    fn move_double_width_boxes_and_robot_vertically_if_possible(
+        &mut self,
+        start_pos: &Point,
+        dir: &Direction,
+    ) {
+        let mut boxes = Vec::new();
+        self.find_double_width_boxes_in_the_way(start_pos, dir, &mut boxes);
+
+        if boxes
+            .iter()
+            .any(|box_pos| self.double_width_box_is_entirely_blocked(box_pos, dir))
+        {
+            return;
+        }
+
+        boxes.sort_by(|a, b| {
+            if *dir == Direction::Up {
+                a.0.y.cmp(&b.0.y)
+            } else {
+                b.0.y.cmp(&a.0.y)
+            }
+        });
+
+        boxes.iter().for_each(|box_pos| {
+            self.move_double_width_box_vertically(box_pos, dir);
+        });
+
+        self.move_robot_with_empty_space_behind(start_pos);
+    }
+
+    fn move_double_width_box_vertically(&mut self, box_pos: &(Point, Point), dir: &Direction) {
+        self.set_obj_at_loc(&self.step(&box_pos.0, dir), Object::LeftBox);
+        self.set_obj_at_loc(&self.step(&box_pos.1, dir), Object::RightBox);
+        self.set_obj_at_loc(&box_pos.0, Object::Empty);
+        self.set_obj_at_loc(&box_pos.1, Object::Empty);
+    }
+
+    fn find_double_width_boxes_in_the_way(
+        &self,
+        pos: &Point,
+        dir: &Direction,
+        boxes: &mut Vec<(Point, Point)>,
+    ) {
+        match self.get_obj_at_loc(pos) {
+            Object::LeftBox => {
+                let right_pos = self.step(pos, &Direction::Right);
+                boxes.push((*pos, right_pos));
+                self.find_double_width_boxes_in_the_way(&self.step(pos, dir), dir, boxes);
+                self.find_double_width_boxes_in_the_way(&self.step(&right_pos, dir), dir, boxes);
+            }
+            Object::RightBox => {
+                let left_pos = self.step(pos, &Direction::Left);
+                boxes.push((left_pos, *pos));
+                self.find_double_width_boxes_in_the_way(&self.step(&left_pos, dir), dir, boxes);
+                self.find_double_width_boxes_in_the_way(&self.step(pos, dir), dir, boxes);
+            }
+            _ => (),
+        }
+    }
+
+    fn double_width_box_is_entirely_blocked(
+        &self,
+        box_loc: &(Point, Point),
+        dir: &Direction,
+    ) -> bool {
+        self.get_obj_at_loc(&self.step(&box_loc.0, dir)) == Object::Wall
+            || self.get_obj_at_loc(&self.step(&box_loc.1, dir)) == Object::Wall
+    }
+
+    fn find_empty_space_and_move_objs_if_possible(&mut self, start_pos: &Point, dir: &Direction) {
+        let next_empty_space = if let Some(v) = self.find_next_empty_space_from_loc(start_pos, dir)
+        {
+            v
+        } else {
+            return;
+        };
+
+        self.move_all_objs_from_empty_to_robot(&next_empty_space, dir);
+    }
+
+    fn find_next_empty_space_from_loc(&self, pos: &Point, dir: &Direction) -> Option<Point> {
+        let mut next_empty_space = *pos;
+        loop {
+            next_empty_space = self.step(&next_empty_space, dir);
+
+            match self.get_obj_at_loc(&next_empty_space) {
+                Object::Empty => break,
+                Object::Wall => return None,
+                _ => {}
+            }
+        }
+        Some(next_empty_space)
+    }
+
+    fn move_all_objs_from_empty_to_robot(&mut self, empty_space_pos: &Point, dir: &Direction) {
+        let robot_cur_pos = self.cur_robot_loc;
+        let mut next_empty_space = *empty_space_pos;
+        let mut prev_space = next_empty_space;
+        loop {
+            prev_space = self.step(&prev_space, &dir.opposite());
+            next_empty_space = self.step(&prev_space, dir);
+            self.set_obj_at_loc(&next_empty_space, self.get_obj_at_loc(&prev_space));
+            if prev_space == robot_cur_pos {
+                self.set_obj_at_loc(&prev_space, Object::Empty);
+                break;
+            }
+        }
+    }
+
+    /// The parser makes sure the map is surrounded by walls, and nothing ever moves into a wall, so
+    /// the next spot is always on the map.
+    fn step(&self, pos: &Point, dir: &Direction) -> Point {
+        self.map
+            .neighbor(*pos, *dir)
+            .expect("the map should be surrounded by walls")
+    }
+
+    fn get_obj_at_loc(&self, pos: &Point) -> Object {
+        self.map[*pos]
+    }
+
+    fn set_obj_at_loc(&mut self, pos: &Point, obj: Object) {
+        self.map[*pos] = obj;
+        if obj == Object::Robot {
+            self.cur_robot_loc = *pos;
+        }
+    }
+}
+
+impl FromStr for RobotMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (map_str, dir_str) = parse::split_once(value, value, "\n\n")?;
+
+        let (map, [robot_loc]) = Grid::parse_with_markers(map_str, ['@'], Object::try_from)?;
+        check_walled_in(map_str, &map)?;
+
+        let dirs = dir_str
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                Direction::from_arrow(c).ok_or_else(|| {
+                    ParseError::at(value, &dir_str[i..], ParseErrorKind::UnexpectedChar(c))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            map,
+            cur_robot_loc: robot_loc,
+            robot_directions: dirs,
+        })
+    }
+}
+
+/// Makes sure there are walls all the way round the edge, which is what stops the robot and the
+/// boxes it pushes from going off the map.
+fn check_walled_in(map_str: &str, map: &Grid<Object>) -> Result<(), ParseError> {
+    let on_edge =
+        |p: Point| p.x == 0 || p.y == 0 || p.x + 1 == map.width() || p.y + 1 == map.height();
+    let Some((open, _)) = map
+        .iter()
+        .find(|(p, obj)| on_edge(*p) && **obj != Object::Wall)
+    else {
+        return Ok(());
+    };
+
+    Err(map.parse_error_at(
+        map_str,
+        open,
+        ParseErrorKind::Expected("a wall around the edge of the map".to_string()),
+    ))
+}
+
+impl fmt::Display for RobotMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.map.render())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Object {
+    Wall,
+    Box,
+    LeftBox,
+    RightBox,
+    Robot,
+    Empty,
+}
+
+impl TryFrom<char> for Object {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Object::Wall),
+            'O' => Ok(Object::Box),
+            '@' => Ok(Object::Robot),
+            '.' => Ok(Object::Empty),
+            '[' => Ok(Object::LeftBox),
+            ']' => Ok(Object::RightBox),
+            _ => Err(ParseErrorKind::UnexpectedChar(value)),
+        }
+    }
+}
+
+impl From<Object> for char {
+    fn from(value: Object) -> Self {
+        match value {
+            Object::Wall => '#',
+            Object::Box => 'O',
+            Object::Robot => '@',
+            Object::Empty => '.',
+            Object::RightBox => ']',
+            Object::LeftBox => '[',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let map = load_input::<Day15>(15, "example").expect("failed to read input");
+        assert_eq!(Day15::part1(&map), 10092);
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = load_input::<Day15>(15, "example").expect("failed to read input");
+        assert_eq!(Day15::part2(&map), 9021);
+    }
+
+    #[test]
+    fn record_moves_writes_a_frame_per_move() {
+        let map = load_input::<Day15>(15, "example").expect("failed to read input");
+        let dir = std::env::temp_dir().join(format!("day-15-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir)
+            .expect("failed to make frames dir")
+            .format(grid::ImageFormat::Ppm);
+
+        map.double_width()
+            .record_moves(&mut frames)
+            .expect("failed to write frames");
+        assert_eq!(frames.len(), map.robot_directions.len() + 1);
+        std::fs::remove_dir_all(dir).expect("failed to clean up frames");
+    }
+
+    #[test]
+    fn a_gap_in_the_walls_is_an_error() {
+        let err = "####\n#@..\n####\n\n>>>".parse::<RobotMap>().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a wall around the edge of the map"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{
+    search::ShortestPaths,
+    walker::{Pose, Walker},
+    Color, Direction, Grid, Point,
+};
+use strum::IntoEnumIterator;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        map.lowest_score()
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        map.num_tiles_on_best_paths()
+    }
+}
+
+pub struct Map {
+    open: Grid<bool>,
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (open, [start, end]) = Grid::parse_with_markers(value, ['S', 'E'], |c| match c {
+            '#' => Ok(false),
+            '.' | 'S' | 'E' => Ok(true),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+
+        Ok(Self { open, start, end })
+    }
+}
+
+impl Map {
+    fn best_paths(&self) -> ShortestPaths<Pose, u64> {
+        let reindeer = Walker {
+            step_cost: 1,
+            turn_cost: 1000,
+            compress_corridors: true,
+            ..Walker::default()
+        };
+        let start = Pose {
+            pos: self.start,
+            dir: Direction::Right,
+        };
+
+        reindeer.shortest_paths(&self.open, start, &HashSet::from([self.end]))
+    }
+
+    /// The reindeer can face any way once it's on the end tile.
+    fn ending_poses(&self) -> impl Iterator<Item = Pose> + '_ {
+        Direction::iter().map(|dir| Pose { pos: self.end, dir })
+    }
+
+    fn lowest_score_of(&self, paths: &ShortestPaths<Pose, u64>) -> u64 {
+        self.ending_poses()
+            .filter_map(|end_pose| paths.cost(&end_pose))
+            .min()
+            .expect("should have a min weight")
+    }
+
+    fn lowest_score(&self) -> u64 {
+        self.lowest_score_of(&self.best_paths())
+    }
+
+    fn num_tiles_on_best_paths(&self) -> u64 {
+        self.tiles_on_best_paths().len() as u64
+    }
+
+    /// The map with every tile on a best path drawn as `O`.
+    pub fn render_best_paths(&self, color: bool) -> String {
+        self.open
+            .render_with(|open| if *open { '.' } else { '#' })
+            .mark(self.tiles_on_best_paths(), 'O', Color::Green)
+            .mark([self.start], 'S', Color::Yellow)
+            .mark([self.end], 'E', Color::Yellow)
+            .color(color)
+            .to_string()
+    }
+
+    fn tiles_on_best_paths(&self) -> HashSet<Point> {
+        let paths = self.best_paths();
+        let min_cost = self.lowest_score_of(&paths);
+
+        let best_ends: Vec<_> = self
+            .ending_poses()
+            .filter(|end_pose| paths.cost(end_pose) == Some(min_cost))
+            .collect();
+        let poses = paths.nodes_on_paths(&best_ends);
+
+        let mut tiles: HashSet<Point> = HashSet::new();
+
+        poses.iter().for_each(|pose| {
+            tiles.insert(pose.pos);
+            paths
+                .predecessors(pose)
+                .iter()
+                .filter(|prev| prev.pos != pose.pos)
+                .for_each(|prev| {
+                    tiles.extend(prev.pos.points_between_other(&pose.pos));
+                });
+        });
+
+        tiles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let map = load_input::<Day16>(16, "example").expect("failed to read input");
+        assert_eq!(Day16::part1(&map), 7036);
+
+        let map2 = load_input::<Day16>(16, "example1").expect("failed to read input");
+        assert_eq!(Day16::part1(&map2), 11048);
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = load_input::<Day16>(16, "example").expect("failed to read input");
+        assert_eq!(Day16::part2(&map), 45);
+
+        let map2 = load_input::<Day16>(16, "example1").expect("failed to read input");
+        assert_eq!(Day16::part2(&map2), 64);
+    }
+
+    #[test]
+    fn render_best_paths_marks_every_tile() {
+        let map = load_input::<Day16>(16, "example").expect("failed to read input");
+        let rendered = map.render_best_paths(false);
+
+        assert_eq!(rendered.matches('O').count(), 45 - 2);
+        assert_eq!(rendered.lines().count(), map.open.height());
+    }
+}
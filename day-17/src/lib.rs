@@ -0,0 +1,426 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = String;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(computer: &Self::Input) -> String {
+        computer.clone().run_program()
+    }
+
+    fn part2(computer: &Self::Input) -> String {
+        let needed_output = computer.program.iter().map(|n| *n as u64).collect();
+        match lowest_reg_a_for(computer, &needed_output) {
+            Some(reg_a) => reg_a.to_string(),
+            None => "no register A makes the program output itself".to_string(),
+        }
+    }
+}
+
+/// The lowest register A that makes the program output `needed_output`. Only works for programs
+/// like the puzzle's, which output one number for every 3 bits of A, from the lowest bits up.
+fn lowest_reg_a_for(computer: &Computer, needed_output: &Vec<u64>) -> Option<u64> {
+    find_num_that_gets_outputs(
+        &mut computer.clone(),
+        needed_output,
+        needed_output.len().checked_sub(1)?,
+        0,
+    )
+}
+
+fn find_num_that_gets_outputs(
+    computer: &mut Computer,
+    needed_output: &Vec<u64>,
+    needed_output_idx: usize,
+    cur_num: u64,
+) -> Option<u64> {
+    for i in 0..8 {
+        if cur_num == 0 && i == 0 {
+            continue;
+        }
+        computer.reset();
+        let reg_a_to_try = (cur_num << 3) | i;
+        computer.reg_A = reg_a_to_try;
+
+        let out = computer.run_until_output();
+        if out == Some(needed_output[needed_output_idx]) {
+            if needed_output_idx == 0 {
+                return Some(reg_a_to_try);
+            }
+
+            if let Some(answer) = find_num_that_gets_outputs(
+                computer,
+                needed_output,
+                needed_output_idx - 1,
+                reg_a_to_try,
+            ) {
+                return Some(answer);
+            }
+        }
+    }
+
+    None
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone)]
+pub struct Computer {
+    reg_A: u64,
+    reg_B: u64,
+    reg_C: u64,
+
+    orig_reg_vals: [u64; 3],
+
+    instruction_ptr: usize,
+
+    program: Vec<u8>,
+
+    outputs: Vec<u64>,
+}
+
+impl Computer {
+    fn run_program(&mut self) -> String {
+        loop {
+            let result = self.tick();
+            if result == ComputerTickResult::Halt {
+                break;
+            }
+        }
+
+        vec_to_str(&self.outputs)
+    }
+
+    /// The first thing the program outputs, or `None` if it halts without outputting anything.
+    fn run_until_output(&mut self) -> Option<u64> {
+        loop {
+            match self.tick() {
+                ComputerTickResult::AddedOutput => return Some(self.outputs[0]),
+                ComputerTickResult::Halt => return None,
+                ComputerTickResult::Ok => {}
+            }
+        }
+    }
+
+    fn tick(&mut self) -> ComputerTickResult {
+        if self.instruction_ptr + 1 >= self.program.len() {
+            return ComputerTickResult::Halt;
+        }
+
+        let (opcode, operand) = self.get_opcode_and_operand();
+
+        match opcode {
+            Instruction::adv => {
+                let result = self.division_on_reg_a(operand);
+
+                self.reg_A = result;
+                self.instruction_ptr += 2;
+            }
+            Instruction::bxl => {
+                let result = self.reg_B ^ (operand as u64);
+
+                self.reg_B = result;
+                self.instruction_ptr += 2;
+            }
+            Instruction::bst => {
+                let result = self.combo_operand(operand) & 0b111; // same as % 8
+
+                self.reg_B = result;
+                self.instruction_ptr += 2;
+            }
+            Instruction::jnz => {
+                if self.reg_A == 0 {
+                    self.instruction_ptr += 2;
+                } else {
+                    self.instruction_ptr = operand as usize;
+                }
+            }
+            Instruction::bxc => {
+                let result = self.reg_B ^ self.reg_C;
+
+                self.reg_B = result;
+                self.instruction_ptr += 2;
+            }
+            Instruction::out => {
+                let combo_op = self.combo_operand(operand);
+                let result = combo_op & 0b111; // same as % 8
+
+                self.outputs.push(result);
+
+                self.instruction_ptr += 2;
+
+                return ComputerTickResult::AddedOutput;
+            }
+            Instruction::bdv => {
+                let result = self.division_on_reg_a(operand);
+
+                self.reg_B = result;
+                self.instruction_ptr += 2;
+            }
+            Instruction::cdv => {
+                let result = self.division_on_reg_a(operand);
+
+                self.reg_C = result;
+                self.instruction_ptr += 2;
+            }
+        }
+
+        ComputerTickResult::Ok
+    }
+
+    fn get_opcode_and_operand(&self) -> (Instruction, u8) {
+        (
+            Instruction::from(self.program[self.instruction_ptr]),
+            self.program[self.instruction_ptr + 1],
+        )
+    }
+
+    fn combo_operand(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4 => self.reg_A,
+            5 => self.reg_B,
+            6 => self.reg_C,
+            _ => unreachable!("the parser only allows combo operands from 0 to 6"),
+        }
+    }
+
+    fn division_on_reg_a(&mut self, operand: u8) -> u64 {
+        let combo_op = self.combo_operand(operand);
+
+        self.reg_A >> combo_op // same as (A / 2^n)
+    }
+
+    fn reset(&mut self) {
+        self.reg_A = self.orig_reg_vals[0];
+        self.reg_B = self.orig_reg_vals[1];
+        self.reg_C = self.orig_reg_vals[2];
+        self.outputs = Vec::new();
+        self.instruction_ptr = 0;
+    }
+}
+
+impl Display for Computer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Reg A: {}\nReg B: {}\nReg C: {}\nOutputs: {}",
+            self.reg_A,
+            self.reg_B,
+            self.reg_C,
+            vec_to_str(&self.outputs)
+        )
+    }
+}
+
+#[derive(PartialEq)]
+enum ComputerTickResult {
+    Ok,
+    Halt,
+    AddedOutput,
+}
+
+impl FromStr for Computer {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lines = value.lines().filter(|line| !line.trim().is_empty());
+        let mut next_line = |expected| {
+            lines
+                .next()
+                .ok_or_else(|| ParseError::end_of(value, value, expected))
+        };
+
+        let regs = [
+            num_from_register_line(value, next_line("register A")?)?,
+            num_from_register_line(value, next_line("register B")?)?,
+            num_from_register_line(value, next_line("register C")?)?,
+        ];
+        let program = program_from_program_line(value, next_line("a program")?)?;
+
+        Ok(Self {
+            reg_A: regs[0],
+            reg_B: regs[1],
+            reg_C: regs[2],
+
+            orig_reg_vals: regs,
+
+            instruction_ptr: 0,
+
+            program,
+
+            outputs: Vec::new(),
+        })
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+enum Instruction {
+    adv,
+    bxl,
+    bst,
+    jnz,
+    bxc,
+    out,
+    bdv,
+    cdv,
+}
+
+impl From<u8> for Instruction {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::adv,
+            1 => Self::bxl,
+            2 => Self::bst,
+            3 => Self::jnz,
+            4 => Self::bxc,
+            5 => Self::out,
+            6 => Self::bdv,
+            7 => Self::cdv,
+            _ => panic!("unknown instruction"),
+        }
+    }
+}
+
+fn num_from_register_line(input: &str, line: &str) -> Result<u64, ParseError> {
+    let (_, num_str) = parse::split_once(input, line, ":")?;
+    parse::num(input, num_str.trim())
+}
+
+/// Reads the program, which has to be pairs of an instruction and its operand. Instructions that
+/// take a combo operand can't be given the reserved 7.
+fn program_from_program_line(input: &str, line: &str) -> Result<Vec<u8>, ParseError> {
+    let (_, prog_str) = parse::split_once(input, line, ":")?;
+    let prog_str = prog_str.trim();
+    if prog_str.is_empty() {
+        return Err(ParseError::end_of(input, line, "a program"));
+    }
+
+    let nums = prog_str
+        .split(",")
+        .map(|s| match parse::num(input, s)? {
+            n @ 0..=7 => Ok((s, n)),
+            _ => Err(ParseError::at(
+                input,
+                s,
+                ParseErrorKind::Expected("a 3-bit number".to_string()),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for pair in nums.chunks(2) {
+        match pair {
+            [(_, opcode), (operand_str, 7)] if takes_combo_operand(*opcode) => {
+                return Err(ParseError::at(
+                    input,
+                    operand_str,
+                    ParseErrorKind::Expected("a combo operand from 0 to 6".to_string()),
+                ));
+            }
+            [_, _] => {}
+            _ => return Err(ParseError::end_of(input, line, "an operand")),
+        }
+    }
+
+    Ok(nums.into_iter().map(|(_, n)| n).collect())
+}
+
+/// Whether the instruction with this opcode reads its operand as a combo operand.
+fn takes_combo_operand(opcode: u8) -> bool {
+    matches!(
+        Instruction::from(opcode),
+        Instruction::adv
+            | Instruction::bst
+            | Instruction::out
+            | Instruction::bdv
+            | Instruction::cdv
+    )
+}
+
+fn vec_to_str<T: ToString>(v: &[T]) -> String {
+    v.iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{load_input, parse_input};
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let computer = load_input::<Day17>(17, "example").expect("invalid input");
+
+        let result = Day17::part1(&computer);
+        assert_eq!(result.as_str(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part2_works() {
+        let computer = load_input::<Day17>(17, "example2").expect("invalid input");
+        let result = Day17::part2(&computer);
+        assert_eq!(result, "117440");
+    }
+
+    #[test]
+    fn programs_need_an_operand_for_every_instruction() {
+        let err = parse_input::<Day17>("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5")
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 11: unexpected end of input, expected an operand"
+        );
+
+        let err = parse_input::<Day17>("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram:")
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 9: unexpected end of input, expected a program"
+        );
+    }
+
+    #[test]
+    fn combo_operand_7_is_an_error() {
+        let err =
+            parse_input::<Day17>("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7")
+                .err()
+                .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 16: expected a combo operand from 0 to 6"
+        );
+    }
+
+    #[test]
+    fn part2_says_when_no_register_a_works() {
+        let computer =
+            parse_input::<Day17>("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1")
+                .expect("invalid input");
+        assert_eq!(
+            Day17::part2(&computer),
+            "no register A makes the program output itself"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Runs the program with its own simple interpreter, and for part 2 tries every value of
+//! register A in turn.
+
+use crate::Computer;
+
+/// Every output of the program, or `None` if it's still going after `max_steps` instructions.
+pub fn run(program: &[u8], [mut a, mut b, mut c]: [u64; 3], max_steps: usize) -> Option<Vec<u64>> {
+    let mut outputs = Vec::new();
+    let mut ip = 0;
+
+    for _ in 0..max_steps {
+        let (Some(&opcode), Some(&operand)) = (program.get(ip), program.get(ip + 1)) else {
+            return Some(outputs);
+        };
+        let literal = operand as u64;
+        let combo = |a, b, c| match operand {
+            0..=3 => literal,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => panic!("invalid combo operand {operand}"),
+        };
+
+        ip += 2;
+        match opcode {
+            0 => a >>= combo(a, b, c),
+            1 => b ^= literal,
+            2 => b = combo(a, b, c) % 8,
+            3 if a != 0 => ip = literal as usize,
+            3 => {}
+            4 => b ^= c,
+            5 => outputs.push(combo(a, b, c) % 8),
+            6 => b = a >> combo(a, b, c),
+            7 => c = a >> combo(a, b, c),
+            _ => panic!("invalid opcode {opcode}"),
+        }
+    }
+
+    None
+}
+
+pub fn part1(computer: &Computer) -> String {
+    let outputs =
+        run(&computer.program, computer.orig_reg_vals, 1_000_000).expect("program should halt");
+    outputs
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The lowest register A, up to `max_a`, that makes the program output `needed_output`.
+pub fn lowest_reg_a_for(computer: &Computer, needed_output: &[u64], max_a: u64) -> Option<u64> {
+    let [_, b, c] = computer.orig_reg_vals;
+    (1..=max_a)
+        .find(|&a| run(&computer.program, [a, b, c], 10_000).as_deref() == Some(needed_output))
+}
+
+pub fn part2(computer: &Computer, max_a: u64) -> Option<u64> {
+    let program: Vec<_> = computer.program.iter().map(|&n| n as u64).collect();
+    lowest_reg_a_for(computer, &program, max_a)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input, Solution};
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn matches_on_the_examples() {
+        let computer = load_input::<Day17>(17, "example").expect("invalid input");
+        assert_eq!(Day17::part1(&computer), part1(&computer));
+
+        let computer = load_input::<Day17>(17, "example2").expect("invalid input");
+        assert_eq!(
+            Some(Day17::part2(&computer)),
+            part2(&computer, 200_000).map(|a| a.to_string())
+        );
+    }
+
+    #[test]
+    fn matches_on_generated_programs() {
+        generate::check(50, |rng| {
+            let input = generate::three_bit_program(rng);
+            let computer = parse_input::<Day17>(&input).unwrap();
+
+            let output = part1(&computer);
+            assert_eq!(Day17::part1(&computer), output, "{input}");
+
+            // every A with as many 3 bit chunks as there are outputs
+            let needed_output: Vec<u64> = output.split(',').map(|n| n.parse().unwrap()).collect();
+            let max_a = 8u64.pow(needed_output.len() as u32) - 1;
+            assert_eq!(
+                crate::lowest_reg_a_for(&computer, &needed_output),
+                lowest_reg_a_for(&computer, &needed_output, max_a),
+                "{input}"
+            );
+        });
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt::Display;
+
+use anyhow::ensure;
+
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use grid::{search, BitGrid, Grid, Point, Vec2};
+use serde::Deserialize;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = ElfComputer;
+    type Answer1 = usize;
+    type Answer2 = Point;
+    type Params = Day18Params;
+
+    fn parse(input: &str, params: &Day18Params) -> Result<Self::Input, anyhow::Error> {
+        ElfComputer::new(input, params.grid_size, params.bytes_dropped)
+    }
+
+    fn part1(comp: &Self::Input) -> usize {
+        comp.find_min_steps_to_exit().unwrap_or_else(|| {
+            panic!(
+                "the exit should still be reachable after {} bytes have fallen",
+                comp.bytes_dropped
+            )
+        })
+    }
+
+    fn part2(comp: &Self::Input) -> Point {
+        let mut comp = comp.clone();
+        for dropped in comp.bytes_dropped + 1..=comp.byte_locs.len() {
+            comp.bytes_dropped = dropped;
+
+            if comp.find_min_steps_to_exit().is_none() {
+                return comp.byte_locs[dropped - 1];
+            }
+        }
+
+        panic!("the exit is still reachable after every byte has fallen")
+    }
+}
+
+/// The example's memory space is a lot smaller than the real one, and fewer bytes have fallen by
+/// part 1.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day18Params {
+    /// How wide and tall the memory space is.
+    pub grid_size: usize,
+    /// How many bytes have fallen when part 1 looks for the exit.
+    pub bytes_dropped: usize,
+}
+
+impl Default for Day18Params {
+    fn default() -> Self {
+        Self {
+            grid_size: 71,
+            bytes_dropped: 1024,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ElfComputer {
+    grid_size: usize,
+
+    byte_locs: Vec<Point>,
+    bytes_dropped: usize,
+}
+
+impl ElfComputer {
+    fn new(input: &str, grid_size: usize, bytes_dropped: usize) -> Result<Self, anyhow::Error> {
+        ensure!(
+            grid_size > 0,
+            "the memory space needs to be at least 1 wide"
+        );
+
+        let bytes = input.lines().map(|line| {
+            let (x_str, y_str) = parse::split_once(input, line, ",")?;
+
+            Ok(Point {
+                x: parse::num(input, x_str)?,
+                y: parse::num(input, y_str)?,
+            })
+        });
+
+        let byte_locs: Vec<_> = bytes.collect::<Result<_, ParseError>>()?;
+        ensure!(
+            bytes_dropped <= byte_locs.len(),
+            "can't drop {bytes_dropped} bytes, there are only {}",
+            byte_locs.len()
+        );
+
+        Ok(Self {
+            grid_size,
+            byte_locs,
+            bytes_dropped,
+        })
+    }
+
+    /// How many steps the shortest way to the exit takes, or `None` if it's been cut off.
+    fn find_min_steps_to_exit(&self) -> Option<usize> {
+        let corrupted = self.corrupted_grid();
+        let end = Vec2::new(self.grid_size as i64 - 1, self.grid_size as i64 - 1);
+
+        search::astar(
+            Point { x: 0, y: 0 },
+            |position| {
+                corrupted
+                    .neighbors(*position)
+                    .filter(|(_, neighbor)| !corrupted[*neighbor])
+                    .map(|(_, neighbor)| (neighbor, 1))
+            },
+            |position| position.to_vec2().manhattan_distance(&end) as usize,
+            |position| position.to_vec2() == end,
+        )
+        .map(|(steps, _)| steps)
+    }
+
+    /// Marks every spot a byte has fallen on so far.
+    fn corrupted_grid(&self) -> BitGrid {
+        let mut corrupted = BitGrid::new(self.grid_size, self.grid_size);
+        for p in &self.byte_locs[0..self.bytes_dropped] {
+            if corrupted.in_bounds(*p) {
+                corrupted.insert(*p);
+            }
+        }
+        corrupted
+    }
+}
+
+impl Display for ElfComputer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = Grid::from(&self.corrupted_grid());
+        let rendered = grid.render_with(|corrupted| if *corrupted { '#' } else { '.' });
+
+        write!(f, "{}", rendered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let comp = load_input::<Day18>(18, "example").expect("failed to read input");
+        assert_eq!(Day18::part1(&comp), 22);
+    }
+
+    #[test]
+    fn part2_works() {
+        let comp = load_input::<Day18>(18, "example").expect("failed to read input");
+        assert_eq!(Day18::part2(&comp), Point { x: 6, y: 1 });
+    }
+
+    #[test]
+    fn a_wall_of_bytes_cuts_off_the_exit() {
+        let params = Day18Params {
+            grid_size: 3,
+            bytes_dropped: 0,
+        };
+        let comp = Day18::parse("1,0\n1,1\n1,2\n", &params).expect("failed to parse input");
+        assert_eq!(Day18::part1(&comp), 4);
+        assert_eq!(Day18::part2(&comp), Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn more_bytes_than_the_input_has_is_an_error() {
+        let params = Day18Params {
+            grid_size: 3,
+            bytes_dropped: 4,
+        };
+        let err = Day18::parse("1,0\n1,1\n1,2\n", &params).err().unwrap();
+        assert_eq!(err.to_string(), "can't drop 4 bytes, there are only 3");
+    }
+
+    #[test]
+    #[should_panic(expected = "still reachable after every byte")]
+    fn part2_stops_once_every_byte_has_fallen() {
+        let params = Day18Params {
+            grid_size: 3,
+            bytes_dropped: 0,
+        };
+        let comp = Day18::parse("1,0\n1,1\n", &params).expect("failed to parse input");
+        Day18::part2(&comp);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Towels;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(towels: &Self::Input) -> usize {
+        towels.amount_of_requested_possible()
+    }
+
+    fn part2(towels: &Self::Input) -> usize {
+        towels.amount_of_combos_possible()
+    }
+}
+
+pub struct Towels {
+    available_designs: Vec<Vec<Color>>,
+    requested_designs: Vec<Vec<Color>>,
+}
+
+impl Towels {
+    fn amount_of_requested_possible(&self) -> usize {
+        let mut cache: HashMap<String, Option<usize>> = HashMap::new();
+        self.requested_designs.iter().fold(0, |acc, design| {
+            if self.design_is_possible(design, &mut cache).is_some() {
+                acc + 1
+            } else {
+                acc
+            }
+        })
+    }
+
+    fn amount_of_combos_possible(&self) -> usize {
+        let mut cache: HashMap<String, Option<usize>> = HashMap::new();
+        self.requested_designs.iter().fold(0, |acc, design| {
+            if let Some(num_times) = self.design_is_possible(design, &mut cache) {
+                acc + num_times
+            } else {
+                acc
+            }
+        })
+    }
+
+    fn design_is_possible(
+        &self,
+        design: &[Color],
+        cache: &mut HashMap<String, Option<usize>>,
+    ) -> Option<usize> {
+        if design.is_empty() {
+            return Some(1);
+        }
+
+        let design_str = design_to_str(design);
+        if let Some(result) = cache.get(&design_str) {
+            return *result;
+        }
+
+        let mut combos = 0;
+        for avail in self.available_designs.iter() {
+            if avail.len() <= design.len() && design[0..avail.len()] == *avail.as_slice() {
+                if let Some(num_times) = self.design_is_possible(&design[avail.len()..], cache) {
+                    combos += num_times;
+                }
+            }
+        }
+
+        if combos > 0 {
+            cache.insert(design_str, Some(combos));
+            Some(combos)
+        } else {
+            cache.insert(design_str, None);
+            None
+        }
+    }
+}
+
+fn design_to_str(design: &[Color]) -> String {
+    design.iter().map(|c| Into::<char>::into(*c)).collect()
+}
+
+impl FromStr for Towels {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (available_str, requested_str) = parse::split_once(value, value, "\n\n")?;
+
+        let avaiable: Vec<Vec<_>> = available_str
+            .split(",")
+            .map(|towel| colors_from_str(value, towel.trim()))
+            .collect::<Result<_, _>>()?;
+
+        let requested: Vec<Vec<_>> = requested_str
+            .lines()
+            .map(|line| colors_from_str(value, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            available_designs: avaiable,
+            requested_designs: requested,
+        })
+    }
+}
+
+/// Parses every char of `s`, a slice of `input`, as a color.
+fn colors_from_str(input: &str, s: &str) -> Result<Vec<Color>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| Color::try_from(c).map_err(|kind| ParseError::at(input, &s[i..], kind)))
+        .collect()
+}
+
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+enum Color {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl From<Color> for char {
+    fn from(val: Color) -> Self {
+        match val {
+            Color::White => 'w',
+            Color::Blue => 'u',
+            Color::Black => 'b',
+            Color::Red => 'r',
+            Color::Green => 'g',
+        }
+    }
+}
+
+impl TryFrom<char> for Color {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'r' => Ok(Color::Red),
+            'w' => Ok(Color::White),
+            'b' => Ok(Color::Black),
+            'u' => Ok(Color::Blue),
+            'g' => Ok(Color::Green),
+            _ => Err(ParseErrorKind::UnexpectedChar(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let towels = load_input::<Day19>(19, "example").expect("failed to read input");
+        assert_eq!(Day19::part1(&towels), 6);
+    }
+
+    #[test]
+    fn part2_works() {
+        let towels = load_input::<Day19>(19, "example").expect("failed to read input");
+        assert_eq!(Day19::part2(&towels), 16);
+    }
+}
@@ -0,0 +1,218 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::WithParams,
+    parse::{ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{search, Color, Grid, Point};
+use serde::Deserialize;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = WithParams<Map, Day20Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Day20Params;
+
+    fn parse(input: &str, params: &Day20Params) -> Result<Self::Input, anyhow::Error> {
+        Ok(WithParams {
+            input: input.parse()?,
+            params: *params,
+        })
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        find_amount_of_cheats(&map.input, 2, map.params.save_at_least)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        find_amount_of_cheats(&map.input, 20, map.params.save_at_least)
+    }
+}
+
+/// The real inputs only count cheats that save a lot of time, which the example doesn't have
+/// many of.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day20Params {
+    /// How many picoseconds a cheat has to save to be counted.
+    pub save_at_least: usize,
+}
+
+impl Default for Day20Params {
+    fn default() -> Self {
+        Self { save_at_least: 100 }
+    }
+}
+
+fn find_amount_of_cheats(map: &Map, max_cheat_steps: usize, save_at_least: usize) -> usize {
+    let mut count = 0;
+    map.for_each_cheat(max_cheat_steps, |_, _, saved| {
+        if saved >= save_at_least {
+            count += 1;
+        }
+    });
+    count
+}
+
+pub struct Map {
+    grid: Grid<GridObject>,
+    start_loc: Point,
+    end_loc: Point,
+}
+
+impl Map {
+    /// The track with the way round it drawn as arrows, and the cheat of up to `max_cheat_steps`
+    /// that saves the most time drawn over it, going through the walls.
+    pub fn render_best_cheat(&self, max_cheat_steps: usize, color: bool) -> String {
+        let track = search::bfs(self.start_loc, |p| self.neighbors(p))
+            .path_to(&self.end_loc)
+            .expect("the end should be reachable");
+
+        let mut best: Option<(Point, Point, usize)> = None;
+        self.for_each_cheat(max_cheat_steps, |from, to, saved| {
+            if best.is_none_or(|(_, _, most)| saved > most) {
+                best = Some((from, to, saved));
+            }
+        });
+
+        let mut render = self
+            .grid
+            .render_with(|obj| match obj {
+                GridObject::Wall => '#',
+                GridObject::Empty => '.',
+            })
+            .path(&track, Color::Blue);
+        if let Some((from, to, _)) = best {
+            render = render.path(&cheat_path(from, to), Color::Red);
+        }
+        render
+            .mark([self.start_loc], 'S', Color::Yellow)
+            .mark([self.end_loc], 'E', Color::Yellow)
+            .color(color)
+            .to_string()
+    }
+
+    /// Calls `f` with every cheat of up to `max_cheat_steps` that gets to the end sooner, with
+    /// where it starts, where it ends and how many picoseconds it saves.
+    fn for_each_cheat(&self, max_cheat_steps: usize, mut f: impl FnMut(Point, Point, usize)) {
+        let dists_from_start = self.distances_from(&self.start_loc);
+        let dists_from_end = self.distances_from(&self.end_loc);
+        let non_cheating_dist = dists_from_start[&self.end_loc];
+
+        for (p, to_p) in &dists_from_start {
+            for (p2, from_p2) in &dists_from_end {
+                let steps_to_p2 = p.x.abs_diff(p2.x) + p.y.abs_diff(p2.y);
+                if steps_to_p2 > max_cheat_steps {
+                    continue;
+                }
+
+                let cheating_dist = to_p + steps_to_p2 + from_p2;
+                if cheating_dist < non_cheating_dist {
+                    f(*p, *p2, non_cheating_dist - cheating_dist);
+                }
+            }
+        }
+    }
+
+    /// Steps to every spot on the track from `start`.
+    fn distances_from(&self, start: &Point) -> HashMap<Point, usize> {
+        search::bfs(*start, |p| self.neighbors(p)).into_costs()
+    }
+
+    fn neighbors(&self, p: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid
+            .neighbors(*p)
+            .map(|(_, n)| n)
+            .filter(|n| self.grid[*n] == GridObject::Empty)
+    }
+}
+
+/// Straight across from `from` and then straight up or down to `to`, one step at a time.
+fn cheat_path(from: Point, to: Point) -> Vec<Point> {
+    let mut path = vec![from];
+    let mut at = from;
+    while at != to {
+        if at.x != to.x {
+            at.x = if at.x < to.x { at.x + 1 } else { at.x - 1 };
+        } else {
+            at.y = if at.y < to.y { at.y + 1 } else { at.y - 1 };
+        }
+        path.push(at);
+    }
+    path
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (grid, [start_loc, end_loc]) =
+            Grid::parse_with_markers(value, ['S', 'E'], |c| match c {
+                'S' | 'E' => Ok(GridObject::Empty),
+                _ => GridObject::try_from(c),
+            })?;
+
+        Ok(Self {
+            grid,
+            start_loc,
+            end_loc,
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum GridObject {
+    Wall,
+    Empty,
+}
+
+impl TryFrom<char> for GridObject {
+    type Error = ParseErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::Wall),
+            '.' => Ok(Self::Empty),
+            _ => Err(ParseErrorKind::UnexpectedChar(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let map = load_input::<Day20>(20, "example").expect("failed to read input");
+        assert_eq!(Day20::part1(&map), 1);
+        assert_eq!(find_amount_of_cheats(&map.input, 2, 20), 5);
+        assert_eq!(find_amount_of_cheats(&map.input, 2, 12), 8);
+        assert_eq!(find_amount_of_cheats(&map.input, 2, 10), 10);
+    }
+
+    #[test]
+    fn render_best_cheat_draws_the_track_and_the_cheat() {
+        let map = load_input::<Day20>(20, "example").expect("failed to read input");
+        let rendered = map.input.render_best_cheat(2, false);
+
+        // the best 2 step cheat saves 64 picoseconds by going through the wall right next to the
+        // end, and the track goes past every other open spot
+        assert_eq!(rendered.lines().count(), map.input.grid.height());
+        assert_eq!(rendered.lines().nth(7), Some("###>>E<<>^#v<<#"));
+        assert!(!rendered.contains('.'));
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = load_input::<Day20>(20, "example").expect("failed to read input");
+        assert_eq!(Day20::part2(&map), 285);
+        assert_eq!(find_amount_of_cheats(&map.input, 20, 72), 29);
+        assert_eq!(find_amount_of_cheats(&map.input, 20, 70), 41);
+    }
+}
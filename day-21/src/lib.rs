@@ -0,0 +1,329 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::ensure;
+use common::{
+    params::WithParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+use grid::{Direction, Point};
+use serde::Deserialize;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = WithParams<Vec<KeypadCode>, Day21Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Day21Params;
+
+    fn parse(input: &str, params: &Day21Params) -> Result<Self::Input, anyhow::Error> {
+        ensure!(
+            params.part1_robots > 0 && params.part2_robots > 0,
+            "there needs to be at least 1 robot in each part, got {} and {}",
+            params.part1_robots,
+            params.part2_robots
+        );
+
+        Ok(WithParams {
+            input: parse::lines(input)?,
+            params: *params,
+        })
+    }
+
+    fn part1(codes: &Self::Input) -> usize {
+        sum_of_complexities(&codes.input, codes.params.part1_robots)
+    }
+
+    fn part2(codes: &Self::Input) -> usize {
+        sum_of_complexities(&codes.input, codes.params.part2_robots)
+    }
+}
+
+/// How many robots with directional keypads are between the human and the door in each part.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day21Params {
+    /// Robots for part 1.
+    pub part1_robots: usize,
+    /// Robots for part 2.
+    pub part2_robots: usize,
+}
+
+impl Default for Day21Params {
+    fn default() -> Self {
+        Self {
+            part1_robots: 2,
+            part2_robots: 25,
+        }
+    }
+}
+
+fn sum_of_complexities(codes: &[KeypadCode], num_robots: usize) -> usize {
+    codes.iter().fold(0, |acc, code| {
+        let btn_seq = code.button_sequence_for_human(num_robots);
+        let code_num = code.code_to_num();
+        acc + (btn_seq * code_num)
+    })
+}
+
+pub struct KeypadCode {
+    code: String,
+}
+
+impl FromStr for KeypadCode {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Some((i, c)) = value
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != 'A')
+        {
+            return Err(ParseError::at(
+                value,
+                &value[i..],
+                ParseErrorKind::UnexpectedChar(c),
+            ));
+        }
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return Err(ParseError::at(
+                value,
+                value,
+                ParseErrorKind::InvalidNumber(value.to_string()),
+            ));
+        }
+
+        Ok(Self {
+            code: value.to_string(),
+        })
+    }
+}
+
+impl KeypadCode {
+    const DIR_PAD_EMPTY_SPACE: Point = Point { x: 0, y: 0 };
+    const DIR_PAD_A_POS: Point = Point { x: 2, y: 0 };
+
+    const NUM_PAD_EMPTY_SPACE: Point = Point { x: 0, y: 3 };
+    const NUM_PAD_A_POS: Point = Point { x: 2, y: 3 };
+
+    fn button_sequence_for_human(&self, num_robots: usize) -> usize {
+        let first_options = self.buttons_for_numpad();
+
+        self.find_min_length_of_buttons(&first_options, 1, num_robots, &mut HashMap::new())
+    }
+
+    fn buttons_for_numpad(&self) -> Vec<DirectionalButton> {
+        let mut cur_pos = Self::NUM_PAD_A_POS;
+
+        self.code
+            .chars()
+            .flat_map(|c| {
+                let next_pos = self.pos_of_numpad_button(c);
+
+                let buttons =
+                    self.path_to_next_button(&cur_pos, &next_pos, &Self::NUM_PAD_EMPTY_SPACE);
+                cur_pos = next_pos;
+                buttons
+            })
+            .collect()
+    }
+
+    fn find_min_length_of_buttons(
+        &self,
+        inputs: &[DirectionalButton],
+        level: usize,
+        max_level: usize,
+        cache: &mut HashMap<(Vec<DirectionalButton>, usize), usize>,
+    ) -> usize {
+        if let Some(cached_num) = cache.get(&(inputs.to_vec(), level)) {
+            return *cached_num;
+        }
+
+        if level == max_level {
+            let btns = self.buttons_for_directional_pad(inputs);
+            return btns.len();
+        }
+
+        let mut cur_pos = Self::DIR_PAD_A_POS;
+        inputs.iter().fold(0, |acc, i| {
+            let next_pos = self.pos_of_direction_button(*i);
+
+            let btns_for_i =
+                self.path_to_next_button(&cur_pos, &next_pos, &Self::DIR_PAD_EMPTY_SPACE);
+
+            let num_btns =
+                self.find_min_length_of_buttons(&btns_for_i, level + 1, max_level, cache);
+            cache.insert((btns_for_i, level + 1), num_btns);
+            cur_pos = next_pos;
+            acc + num_btns
+        })
+    }
+
+    fn buttons_for_directional_pad(&self, inputs: &[DirectionalButton]) -> Vec<DirectionalButton> {
+        let mut cur_pos = Self::DIR_PAD_A_POS;
+
+        inputs
+            .iter()
+            .flat_map(|btn| {
+                let next_pos = self.pos_of_direction_button(*btn);
+
+                let buttons =
+                    self.path_to_next_button(&cur_pos, &next_pos, &Self::DIR_PAD_EMPTY_SPACE);
+                cur_pos = next_pos;
+                buttons
+            })
+            .collect()
+    }
+
+    fn path_to_next_button(
+        &self,
+        cur_pos: &Point,
+        next_pos: &Point,
+        empty_space_pos: &Point,
+    ) -> Vec<DirectionalButton> {
+        let x_translation = next_pos.x as i64 - cur_pos.x as i64;
+        let y_translation = next_pos.y as i64 - cur_pos.y as i64;
+
+        let horiz = self.make_horiz_movements(x_translation);
+        let vert = self.make_vert_movements(y_translation);
+        let dirs: Vec<DirectionalButton> = if cur_pos.x as i64 + x_translation
+            == empty_space_pos.x as i64
+            && cur_pos.y == empty_space_pos.y
+        {
+            // can only do the y translation first because of hitting empty_space
+            [vert, horiz, vec![DirectionalButton::A]].concat()
+        } else if cur_pos.y as i64 + y_translation == empty_space_pos.y as i64
+            && cur_pos.x == empty_space_pos.x
+        {
+            // can only do the x translation first because of hitting empty_space
+            [horiz, vert, vec![DirectionalButton::A]].concat()
+        } else {
+            // some magic from
+            // https://github.com/maksverver/AdventOfCode/blob/9ec8c02e5b0fca04efa43bca63e28cf62bf95dcb/2024/21-alt.py#L74
+            // I was too tired to think this through and gave up looking for hints o7
+            if x_translation < 0 && y_translation < 0 {
+                // prefer <^ over ^<
+                [horiz, vert, vec![DirectionalButton::A]].concat()
+            } else if x_translation < 0 && y_translation > 0 {
+                // prefer <v over v<
+                [horiz, vert, vec![DirectionalButton::A]].concat()
+            } else if x_translation > 0 && y_translation < 0 {
+                // prefer ^> over >^
+                [vert, horiz, vec![DirectionalButton::A]].concat()
+            } else {
+                // prefer v> over >v
+                [vert, horiz, vec![DirectionalButton::A]].concat()
+            }
+        };
+
+        dirs
+    }
+
+    fn make_horiz_movements(&self, x_translation: i64) -> Vec<DirectionalButton> {
+        (0..x_translation.abs())
+            .map(|_| {
+                if x_translation < 0 {
+                    DirectionalButton::Move(Direction::Left)
+                } else {
+                    DirectionalButton::Move(Direction::Right)
+                }
+            })
+            .collect()
+    }
+
+    fn make_vert_movements(&self, y_translation: i64) -> Vec<DirectionalButton> {
+        (0..y_translation.abs())
+            .map(|_| {
+                if y_translation < 0 {
+                    DirectionalButton::Move(Direction::Up)
+                } else {
+                    DirectionalButton::Move(Direction::Down)
+                }
+            })
+            .collect()
+    }
+
+    fn pos_of_numpad_button(&self, c: char) -> Point {
+        match c {
+            '7' => Point { x: 0, y: 0 },
+            '8' => Point { x: 1, y: 0 },
+            '9' => Point { x: 2, y: 0 },
+            '4' => Point { x: 0, y: 1 },
+            '5' => Point { x: 1, y: 1 },
+            '6' => Point { x: 2, y: 1 },
+            '1' => Point { x: 0, y: 2 },
+            '2' => Point { x: 1, y: 2 },
+            '3' => Point { x: 2, y: 2 },
+            '0' => Point { x: 1, y: 3 },
+            'A' => Point { x: 2, y: 3 },
+            _ => panic!("unknown char: {}", c),
+        }
+    }
+
+    fn pos_of_direction_button(&self, dir: DirectionalButton) -> Point {
+        match dir {
+            DirectionalButton::Move(Direction::Up) => Point { x: 1, y: 0 },
+            DirectionalButton::Move(Direction::Down) => Point { x: 1, y: 1 },
+            DirectionalButton::Move(Direction::Left) => Point { x: 0, y: 1 },
+            DirectionalButton::Move(Direction::Right) => Point { x: 2, y: 1 },
+            DirectionalButton::A => Point { x: 2, y: 0 },
+        }
+    }
+
+    fn code_to_num(&self) -> usize {
+        self.code
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse()
+            .expect("NaN")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum DirectionalButton {
+    Move(Direction),
+    A,
+}
+
+impl Display for DirectionalButton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DirectionalButton::Move(dir) => dir.arrow(),
+                DirectionalButton::A => 'A',
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let codes = load_input::<Day21>(21, "example").expect("failed to read input");
+        assert_eq!(Day21::part1(&codes), 126384);
+    }
+
+    #[test]
+    fn no_robots_is_an_error() {
+        let params = Day21Params {
+            part1_robots: 0,
+            ..Default::default()
+        };
+        let err = Day21::parse("029A", &params).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "there needs to be at least 1 robot in each part, got 0 and 25"
+        );
+    }
+}
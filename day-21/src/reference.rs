@@ -0,0 +1,124 @@
+//! Searches for the fewest presses by actually pressing buttons, one at a time, and following
+//! what every robot does. There are 5 buttons to press at each step and a state for every
+//! position of every arm, so it only works with a few robots.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::KeypadCode;
+
+const NUM_PAD: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIR_PAD: [&str; 2] = [" ^A", "<v>"];
+
+/// Where every arm is, numeric keypad first, and how much of the code has been typed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct State {
+    arms: Vec<(usize, usize)>,
+    typed: usize,
+}
+
+fn find(pad: &[&str], button: char) -> (usize, usize) {
+    pad.iter()
+        .enumerate()
+        .find_map(|(y, row)| row.find(button).map(|x| (x, y)))
+        .expect("button should be on the pad")
+}
+
+fn button_at(pad: &[&str], (x, y): (usize, usize)) -> Option<char> {
+    pad.get(y)?.chars().nth(x).filter(|button| *button != ' ')
+}
+
+/// Presses `button` on the keypad of the arm at `arm`, which the arm before it is pointing at.
+/// `None` if that points an arm at a gap or types the wrong part of the code.
+fn press(state: &State, code: &[char], arm: usize, button: char) -> Option<State> {
+    let pad: &[&str] = if arm == 0 { &NUM_PAD } else { &DIR_PAD };
+    let mut next = state.clone();
+
+    let (x, y) = state.arms[arm];
+    let moved = match button {
+        '^' => (x, y.checked_sub(1)?),
+        'v' => (x, y + 1),
+        '<' => (x.checked_sub(1)?, y),
+        '>' => (x + 1, y),
+        _ => {
+            let pressed = button_at(pad, (x, y))?;
+            return if arm == 0 {
+                (code.get(state.typed) == Some(&pressed)).then(|| {
+                    next.typed += 1;
+                    next
+                })
+            } else {
+                press(state, code, arm - 1, pressed)
+            };
+        }
+    };
+
+    button_at(pad, moved)?;
+    next.arms[arm] = moved;
+    Some(next)
+}
+
+/// The fewest buttons a human has to press to type the code, with `robots` robots using
+/// directional keypads in between.
+pub fn button_sequence_for_human(code: &KeypadCode, robots: usize) -> usize {
+    let code: Vec<char> = code.code.chars().collect();
+    let start = State {
+        arms: [find(&NUM_PAD, 'A')]
+            .into_iter()
+            .chain((0..robots).map(|_| find(&DIR_PAD, 'A')))
+            .collect(),
+        typed: 0,
+    };
+
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, presses)) = queue.pop_front() {
+        if state.typed == code.len() {
+            return presses;
+        }
+        for button in ['^', 'v', '<', '>', 'A'] {
+            if let Some(next) = press(&state, &code, robots, button) {
+                if seen.insert(next.clone()) {
+                    queue.push_back((next, presses + 1));
+                }
+            }
+        }
+    }
+
+    panic!("every code can be typed")
+}
+
+pub fn sum_of_complexities(codes: &[KeypadCode], robots: usize) -> usize {
+    codes
+        .iter()
+        .map(|code| button_sequence_for_human(code, robots) * code.code_to_num())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{generate, load_input, parse_input, Solution};
+
+    use super::*;
+    use crate::Day21;
+
+    #[test]
+    fn matches_on_the_example() {
+        let codes = load_input::<Day21>(21, "example").expect("failed to read input");
+        assert_eq!(Day21::part1(&codes), sum_of_complexities(&codes.input, 2));
+    }
+
+    #[test]
+    fn matches_on_generated_codes_with_a_few_robots() {
+        generate::check(30, |rng| {
+            let input = generate::keypad_codes(rng);
+            let codes = parse_input::<Day21>(&input).unwrap();
+            for robots in 1..=3 {
+                assert_eq!(
+                    crate::sum_of_complexities(&codes.input, robots),
+                    sum_of_complexities(&codes.input, robots),
+                    "{input} with {robots} robots"
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{
+    params::WithParams,
+    parse::{self, ParseError},
+    Solution,
+};
+use serde::Deserialize;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = WithParams<BuyerSecretNums, Day22Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Day22Params;
+
+    fn parse(input: &str, params: &Day22Params) -> Result<Self::Input, anyhow::Error> {
+        Ok(WithParams {
+            input: input.parse()?,
+            params: *params,
+        })
+    }
+
+    fn part1(secrets: &Self::Input) -> usize {
+        let iterations = secrets.params.iterations;
+        secrets
+            .input
+            .nums
+            .iter()
+            .fold(0, |acc, n| acc + get_nth_secret_num(*n, iterations))
+    }
+
+    fn part2(secrets: &Self::Input) -> usize {
+        secrets
+            .input
+            .find_price_for_selling_with_best_combo(secrets.params.iterations)
+    }
+}
+
+/// How far ahead each buyer's secret numbers get worked out.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day22Params {
+    /// How many new secret numbers each buyer makes in a day.
+    pub iterations: usize,
+}
+
+impl Default for Day22Params {
+    fn default() -> Self {
+        Self { iterations: 2000 }
+    }
+}
+
+pub struct BuyerSecretNums {
+    nums: Vec<usize>,
+}
+
+impl BuyerSecretNums {
+    fn find_price_for_selling_with_best_combo(&self, num_of_iters: usize) -> usize {
+        let all_changes: Vec<_> = self
+            .nums
+            .iter()
+            .map(|n| get_list_of_changes_with_price(*n, num_of_iters))
+            .collect();
+
+        let price_per_combo_per_buyer: Vec<_> = all_changes
+            .iter()
+            .map(|changes| get_map_of_possible_change_combos(changes.as_slice()))
+            .collect();
+
+        let mut set_of_combos: HashSet<[i64; 4]> = HashSet::new();
+        price_per_combo_per_buyer.iter().for_each(|combo_map| {
+            set_of_combos.extend(combo_map.keys());
+        });
+
+        set_of_combos
+            .iter()
+            .map(|combo| {
+                price_per_combo_per_buyer
+                    .iter()
+                    .fold(0, |acc, buyer_map| acc + buyer_map.get(combo).unwrap_or(&0))
+            })
+            .max()
+            // with fewer than 4 changes there's no sequence to sell on, so no bananas
+            .unwrap_or(0)
+    }
+}
+
+impl FromStr for BuyerSecretNums {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let nums = value
+            .lines()
+            .map(|line| parse::num(value, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { nums })
+    }
+}
+
+fn get_nth_secret_num(n: usize, num_of_iters: usize) -> usize {
+    (0..num_of_iters).fold(n, |acc, _| get_next_secret_num(acc))
+}
+
+fn get_next_secret_num(n: usize) -> usize {
+    let step1 = prune_num(mix_num(n, n * 64));
+    let step2 = prune_num(mix_num(step1, step1 / 32));
+    prune_num(mix_num(step2, step2 * 2048))
+}
+
+fn mix_num(n: usize, val: usize) -> usize {
+    n ^ val
+}
+
+fn prune_num(n: usize) -> usize {
+    n % 16777216
+}
+
+fn get_list_of_changes_with_price(n: usize, num_of_iters: usize) -> Vec<(i64, usize)> {
+    let mut changes_and_prices = Vec::with_capacity(num_of_iters);
+    let mut cur_n = n;
+    for _ in 0..num_of_iters {
+        let next_n = get_next_secret_num(cur_n);
+        let cur_price = cur_n % 10;
+        let next_price = next_n % 10;
+        let change = next_price as i64 - cur_price as i64;
+        changes_and_prices.push((change, next_price));
+
+        cur_n = next_n;
+    }
+    changes_and_prices
+}
+
+fn get_map_of_possible_change_combos(
+    changes_with_prices: &[(i64, usize)],
+) -> HashMap<[i64; 4], usize> {
+    let mut combos: HashMap<[i64; 4], usize> = HashMap::new();
+
+    changes_with_prices
+        .windows(4)
+        .for_each(|window_of_combo_with_price| {
+            let window: Vec<_> = window_of_combo_with_price.iter().map(|v| v.0).collect();
+            let arr = <[i64; 4]>::try_from(window).expect("should never fail");
+
+            combos.entry(arr).or_insert(window_of_combo_with_price[3].1);
+        });
+
+    combos
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let secrets = load_input::<Day22>(22, "example").expect("failed to read input");
+        assert_eq!(Day22::part1(&secrets), 37327623);
+    }
+
+    #[test]
+    fn part2_works() {
+        let secrets = load_input::<Day22>(22, "example2").expect("failed to read input");
+        assert_eq!(Day22::part2(&secrets), 23);
+    }
+
+    #[test]
+    fn too_few_changes_sell_nothing() {
+        for iterations in 0..4 {
+            let params = Day22Params { iterations };
+            let secrets = Day22::parse("123", &params).expect("failed to parse input");
+            assert_eq!(Day22::part2(&secrets), 0);
+        }
+
+        let secrets =
+            Day22::parse("123", &Day22Params { iterations: 4 }).expect("failed to parse input");
+        assert_eq!(Day22::part2(&secrets), 4);
+    }
+}
@@ -0,0 +1,283 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = NetworkMap;
+    type Answer1 = usize;
+    type Answer2 = String;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(network: &Self::Input) -> usize {
+        network
+            .get_set_of_three_conns()
+            .iter()
+            .filter(|conns| conns.iter().any(|comp| comp.starts_with("t")))
+            .count()
+    }
+
+    fn part2(network: &Self::Input) -> String {
+        let mut longest_conn = network.get_largest_fully_connected_comps();
+        longest_conn.sort();
+        longest_conn.join(",")
+    }
+}
+
+pub struct NetworkMap {
+    connections: Vec<(String, String)>,
+}
+
+impl NetworkMap {
+    fn get_set_of_three_conns(&self) -> Vec<[String; 3]> {
+        let conn_map = self.get_connection_map();
+
+        let comps: Vec<_> = conn_map.keys().collect();
+
+        let mut set = Vec::new();
+
+        for (i, comp) in comps.iter().enumerate() {
+            let conns_with_this_comp = conn_map.get(*comp).unwrap();
+
+            if i == comps.len() - 2 {
+                break;
+            }
+
+            for (j, comp2) in conns_with_this_comp.iter().enumerate() {
+                if j == conns_with_this_comp.len() - 1 {
+                    break;
+                }
+
+                // already looked at this earlier
+                if comps.iter().position(|c| *c == comp2).unwrap() < i {
+                    continue;
+                }
+
+                for comp3 in &conns_with_this_comp[j + 1..] {
+                    // already looked at this earlier
+                    if comps.iter().position(|c| *c == comp3).unwrap() < i {
+                        continue;
+                    }
+
+                    if let Some(conns_with_comp2) = conn_map.get(comp2) {
+                        if conns_with_comp2.contains(comp3) {
+                            set.push([comp.to_string(), comp2.to_string(), comp3.to_string()]);
+                        }
+                    }
+                }
+            }
+        }
+
+        set
+    }
+
+    fn get_largest_fully_connected_comps(&self) -> Vec<String> {
+        let conn_map = self.get_connection_map();
+
+        let comps: Vec<String> = conn_map.keys().cloned().collect();
+
+        comps
+            .iter()
+            .enumerate()
+            .map(|(i, comp)| {
+                self.longest_chain_for_comp(&conn_map, &[comp.to_string()], 0, comp, &comps[..i])
+            })
+            .max_by(|a, b| a.len().cmp(&b.len()))
+            .expect("should have a max")
+    }
+
+    fn longest_chain_for_comp(
+        &self,
+        conn_map: &HashMap<String, Vec<String>>,
+        cur_chain: &[String],
+        cur_idx: usize,
+        first_comp: &String,
+        already_done: &[String],
+    ) -> Vec<String> {
+        let connnected_comps = &conn_map[first_comp];
+
+        if cur_idx == connnected_comps.len() {
+            return cur_chain.to_vec();
+        }
+
+        let next_comp = connnected_comps[cur_idx].clone();
+
+        let longest_by_skipping_this_comp =
+            self.longest_chain_for_comp(conn_map, cur_chain, cur_idx + 1, first_comp, already_done);
+
+        if already_done.contains(&next_comp) {
+            return longest_by_skipping_this_comp;
+        }
+
+        if self.comp_is_connected_to_all(&cur_chain[1..], conn_map, next_comp.to_string()) {
+            let longest_with_comp = self.longest_chain_for_comp(
+                conn_map,
+                &[cur_chain, &[next_comp.to_string()]].concat(),
+                cur_idx + 1,
+                first_comp,
+                already_done,
+            );
+            if longest_with_comp.len() > longest_by_skipping_this_comp.len() {
+                return longest_with_comp;
+            }
+        }
+
+        longest_by_skipping_this_comp
+    }
+
+    fn comp_is_connected_to_all(
+        &self,
+        chain: &[String],
+        conn_map: &HashMap<String, Vec<String>>,
+        new_comp: String,
+    ) -> bool {
+        chain.iter().all(|comp| {
+            let conns = &conn_map[comp];
+            conns.contains(&new_comp)
+        })
+    }
+
+    fn get_connection_map(&self) -> HashMap<String, Vec<String>> {
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+
+        self.connections.iter().for_each(|conn| {
+            let (left, right) = conn;
+
+            if let Some(cur_list) = map.get_mut(left) {
+                cur_list.push(right.clone());
+            } else {
+                map.insert(left.clone(), vec![right.clone()]);
+            }
+
+            if let Some(cur_list) = map.get_mut(right) {
+                cur_list.push(left.clone());
+            } else {
+                map.insert(right.clone(), vec![left.clone()]);
+            }
+        });
+
+        map
+    }
+}
+
+impl FromStr for NetworkMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let connections = value
+            .lines()
+            .map(|line| {
+                let (left, right) = parse::split_once(value, line, "-")?;
+                Ok((left.into(), right.into()))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Self { connections })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use common::{generate, load_input, parse_input};
+
+    use super::*;
+
+    /// Every computer, and every pair that's connected either way round.
+    fn brute_force_network(input: &str) -> (Vec<&str>, HashSet<(&str, &str)>) {
+        let mut links = HashSet::new();
+        for (a, b) in input.lines().map(|line| line.split_once('-').unwrap()) {
+            links.insert((a, b));
+            links.insert((b, a));
+        }
+        let mut comps: Vec<_> = links.iter().map(|(a, _)| *a).collect();
+        comps.sort();
+        comps.dedup();
+        (comps, links)
+    }
+
+    /// Checks every three computers.
+    fn brute_force_triangles_with_t(input: &str) -> usize {
+        let (comps, links) = brute_force_network(input);
+        let mut count = 0;
+        for (i, a) in comps.iter().enumerate() {
+            for (j, b) in comps.iter().enumerate().skip(i + 1) {
+                for c in &comps[j + 1..] {
+                    let connected = [(a, b), (b, c), (a, c)]
+                        .iter()
+                        .all(|(x, y)| links.contains(&(**x, **y)));
+                    if connected && [a, b, c].iter().any(|comp| comp.starts_with('t')) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Checks every subset of the computers, so only works on small networks.
+    fn brute_force_largest_party(input: &str) -> usize {
+        let (comps, links) = brute_force_network(input);
+        (0u32..1 << comps.len())
+            .filter(|subset| {
+                let party: Vec<_> = (0..comps.len())
+                    .filter(|i| subset & (1 << i) != 0)
+                    .collect();
+                party.iter().all(|&a| {
+                    party
+                        .iter()
+                        .all(|&b| a == b || links.contains(&(comps[a], comps[b])))
+                })
+            })
+            .map(u32::count_ones)
+            .max()
+            .unwrap() as usize
+    }
+
+    #[test]
+    fn part1_works() {
+        let network = load_input::<Day23>(23, "example").expect("failed to read input");
+        assert_eq!(Day23::part1(&network), 7);
+    }
+
+    #[test]
+    fn part2_works() {
+        let network = load_input::<Day23>(23, "example").expect("failed to read input");
+        assert_eq!(Day23::part2(&network), "co,de,ka,ta".to_string());
+    }
+
+    #[test]
+    fn parties_match_brute_force() {
+        generate::check(200, |rng| {
+            let input = generate::network(rng);
+            let network = parse_input::<Day23>(&input).unwrap();
+            assert_eq!(
+                Day23::part1(&network),
+                brute_force_triangles_with_t(&input),
+                "{input}"
+            );
+
+            // there can be more than one biggest party, so just check it's as big and is one
+            let password = Day23::part2(&network);
+            let party: Vec<_> = password.split(',').collect();
+            let (_, links) = brute_force_network(&input);
+            assert_eq!(party.len(), brute_force_largest_party(&input), "{input}");
+            assert!(
+                party
+                    .iter()
+                    .all(|a| party.iter().all(|b| a == b || links.contains(&(*a, *b)))),
+                "{password} isn't a party in\n{input}"
+            );
+        });
+    }
+}
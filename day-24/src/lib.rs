@@ -0,0 +1,500 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Device;
+    type Answer1 = usize;
+    type Answer2 = String;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(device: &Self::Input) -> usize {
+        let mut device = device.clone();
+        device.run_all_instructions();
+        device.get_num_from_z_gates()
+    }
+
+    fn part2(device: &Self::Input) -> String {
+        let mut device = device.clone();
+        let swaps = device
+            .fix_instrs_for_adders(&mut vec![])
+            .expect("must find valid swaps");
+
+        let mut outs: Vec<_> = swaps
+            .iter()
+            .flat_map(|swap| {
+                [
+                    device.instructions[swap.0].c.clone(),
+                    device.instructions[swap.1].c.clone(),
+                ]
+            })
+            .collect();
+        outs.sort();
+
+        outs.join(",")
+    }
+}
+
+#[derive(Clone)]
+pub struct Device {
+    gate_values: HashMap<String, bool>,
+    instructions: Vec<Instruction>,
+}
+
+impl Device {
+    fn run_all_instructions(&mut self) -> Option<()> {
+        let mut instrs_to_do = VecDeque::from_iter(self.instructions.iter());
+
+        let mut iters_without_an_instr = 0;
+        while let Some(instr) = instrs_to_do.pop_front() {
+            if !self.can_do_instruction(instr) {
+                instrs_to_do.push_back(instr);
+                iters_without_an_instr += 1;
+
+                if iters_without_an_instr == instrs_to_do.len() {
+                    return None;
+                }
+
+                continue;
+            }
+
+            iters_without_an_instr = 0;
+
+            let (c, c_val) = instr.run(&self.gate_values);
+            self.gate_values.insert(c, c_val);
+        }
+
+        Some(())
+    }
+
+    fn get_num_from_z_gates(&self) -> usize {
+        let mut z_gates: Vec<_> = self
+            .gate_values
+            .iter()
+            .filter(|(k, _)| k.starts_with("z"))
+            .collect();
+
+        get_num_from_list_of_gates(&mut z_gates)
+    }
+
+    fn can_do_instruction(&self, instr: &Instruction) -> bool {
+        self.gate_values.contains_key(&instr.a) && self.gate_values.contains_key(&instr.b)
+    }
+
+    fn swap_instructions(&mut self, swap: &(usize, usize)) {
+        let a = self.instructions[swap.0].c.clone();
+        let b = self.instructions[swap.1].c.clone();
+        self.instructions[swap.0].c = b;
+        self.instructions[swap.1].c = a;
+    }
+
+    fn fix_instrs_for_adders(
+        &mut self,
+        swaps: &mut Vec<(usize, usize)>,
+    ) -> Option<Vec<(usize, usize)>> {
+        let mut wrong_gates = self.find_wrong_z_gates_for_adder();
+        wrong_gates.sort();
+
+        if swaps.len() > 4 {
+            return None;
+        }
+
+        if swaps.len() == 4 && wrong_gates.is_empty() {
+            return Some(swaps.to_vec());
+        }
+
+        let first_broken = wrong_gates[0].clone();
+        let relevant_instrs = if let Ok(v) = self.find_instrs_used_for_z(&first_broken) {
+            v
+        } else {
+            return None;
+        };
+
+        for (i, _) in relevant_instrs {
+            for j in 0..self.instructions.len() {
+                if i == j {
+                    continue;
+                }
+                let swap = (i, j);
+
+                self.swap_instructions(&swap);
+
+                if self.z_gate_is_correct_for_adder(&first_broken) {
+                    swaps.push(swap);
+                    if let Some(ans) = self.fix_instrs_for_adders(swaps) {
+                        return Some(ans);
+                    } else {
+                        swaps.pop();
+                    }
+                }
+
+                self.swap_instructions(&swap);
+            }
+        }
+
+        None
+    }
+
+    fn get_all_z_gates(&self) -> Vec<String> {
+        self.instructions
+            .iter()
+            .filter_map(|instr| {
+                if instr.c.starts_with("z") {
+                    Some(instr.c.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn find_wrong_z_gates_for_adder(&self) -> Vec<String> {
+        let z_gates = self.get_all_z_gates();
+
+        z_gates
+            .iter()
+            .filter_map(|z| {
+                if !self.z_gate_is_correct_for_adder(z) {
+                    Some(z.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn z_gate_is_correct_for_adder(&self, z: &str) -> bool {
+        self.dfs_for_tree_of_instrs(z, &mut HashSet::new())
+            .is_some_and(|tree| tree.is_full_adder(z))
+    }
+
+    fn find_instrs_used_for_z(&self, z: &str) -> Result<Vec<(usize, Instruction)>, ()> {
+        let mut queue = vec![z.to_string()];
+        let mut instrs = vec![];
+        let mut visited = HashSet::new();
+
+        while let Some(v) = queue.pop() {
+            visited.insert(v.to_string());
+
+            if let Some((i, instr)) = self
+                .instructions
+                .iter()
+                .enumerate()
+                .find(|(_, instr)| instr.c == v)
+            {
+                instrs.push((i, instr.clone()));
+                if !visited.contains(&instr.a) {
+                    queue.push(instr.a.to_string());
+                }
+                if !visited.contains(&instr.b) {
+                    queue.push(instr.b.to_string());
+                }
+            }
+        }
+
+        Ok(instrs)
+    }
+
+    fn dfs_for_tree_of_instrs(
+        &self,
+        gate: &str,
+        visited: &mut HashSet<String>,
+    ) -> Option<Box<Node>> {
+        if visited.contains(gate) {
+            return None;
+        }
+        if let Some((_, instr)) = self.find_instr_for_output(gate) {
+            visited.insert(gate.to_string());
+            Some(Box::new(Node {
+                val: instr.clone(),
+                left: self.dfs_for_tree_of_instrs(&instr.a, visited),
+                right: self.dfs_for_tree_of_instrs(&instr.b, visited),
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn find_instr_for_output(&self, gate: &str) -> Option<(usize, Instruction)> {
+        self.instructions
+            .iter()
+            .enumerate()
+            .find(|(_, instr)| instr.c == gate)
+            .map(|(i, instr)| (i, instr.clone()))
+    }
+
+    pub fn graphviz(&self) -> String {
+        let mut s = "digraph {\n".to_string();
+
+        self.instructions.iter().for_each(|instr| {
+            s += format!("{} -> {}\n{} -> {}\n", instr.a, instr.c, instr.b, instr.c).as_str();
+        });
+
+        s + "}\n"
+    }
+}
+
+impl FromStr for Device {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (gates, instrs) = parse::split_once(value, value, "\n\n")?;
+
+        let gate_values = gates
+            .lines()
+            .map(|g| {
+                let (k, v) = parse::split_once(value, g, ": ")?;
+                let v = match v {
+                    "0" => false,
+                    "1" => true,
+                    _ => {
+                        return Err(ParseError::at(
+                            value,
+                            v,
+                            ParseErrorKind::Expected("0 or 1".to_string()),
+                        ))
+                    }
+                };
+
+                Ok((k.to_string(), v))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let instructions = instrs
+            .lines()
+            .map(|line| Instruction::parse(value, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            gate_values,
+            instructions,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Instruction {
+    a: String,
+    gate: Gate,
+    b: String,
+
+    c: String,
+}
+
+impl Instruction {
+    fn run(&self, gate_values: &HashMap<String, bool>) -> (String, bool) {
+        let a_val = gate_values[&self.a];
+        let b_val = gate_values[&self.b];
+        let c_val = match self.gate {
+            Gate::And => a_val && b_val,
+            Gate::Or => a_val || b_val,
+            Gate::Xor => a_val ^ b_val,
+        };
+        (self.c.to_string(), c_val)
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.a, self.gate, self.b)
+    }
+}
+
+impl Instruction {
+    /// Parses an instruction like `x00 AND y00 -> z00` from `line`, which is a slice of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (inputs, c) = parse::split_once(input, line, " -> ")?;
+
+        let mut split = inputs.split_whitespace();
+        let mut next = |expected| {
+            split
+                .next()
+                .ok_or_else(|| ParseError::end_of(input, inputs, expected))
+        };
+        let a = next("a wire")?;
+        let gate = next("a gate")?;
+        let b = next("a wire")?;
+
+        Ok(Self {
+            a: a.to_string(),
+            gate: Gate::from_str(gate).map_err(|kind| ParseError::at(input, gate, kind))?,
+            b: b.to_string(),
+            c: c.trim().to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Gate {
+    And,
+    Or,
+    Xor,
+}
+
+impl Display for Gate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Gate::And => "&",
+                Gate::Or => "|",
+                Gate::Xor => "^",
+            }
+        )
+    }
+}
+
+impl FromStr for Gate {
+    type Err = ParseErrorKind;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            "XOR" => Ok(Self::Xor),
+            _ => Err(ParseErrorKind::Expected("AND, OR or XOR".to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    val: Instruction,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl Node {
+    // returns true if a valid full adder for the out bit
+    fn is_full_adder(&self, out: &str) -> bool {
+        if out == "z00" {
+            return self.is_xor_for_main_bits("z00");
+        } else if out == "z45" {
+            return self.is_correct_carry_gates("z45");
+        }
+
+        if self.left.is_none() || self.right.is_none() {
+            return false;
+        }
+        let left = self.left.as_ref().unwrap();
+        let right = self.right.as_ref().unwrap();
+        self.val.gate == Gate::Xor
+            && ((left.is_xor_for_main_bits(out) && right.is_correct_carry_gates(out))
+                || (right.is_xor_for_main_bits(out) && left.is_correct_carry_gates(out)))
+    }
+
+    // for z03, should have x03^y03
+    fn is_xor_for_main_bits(&self, out: &str) -> bool {
+        let needed_inputs = [out.replace("z", "x"), out.replace("z", "y")];
+        let mut actual_inputs = [self.val.a.clone(), self.val.b.clone()];
+        actual_inputs.sort();
+
+        self.val.gate == Gate::Xor && actual_inputs == needed_inputs
+    }
+
+    fn is_correct_carry_gates(&self, out: &str) -> bool {
+        if out == "z01" {
+            return self.is_and_of_prev_bit(out);
+        }
+
+        if self.val.gate != Gate::Or {
+            return false;
+        }
+
+        if self.left.is_none() || self.right.is_none() {
+            return false;
+        }
+
+        let left = self.left.as_ref().unwrap();
+        let right = self.right.as_ref().unwrap();
+
+        (left.is_and_of_prev_bit(out) && right.is_carry_input(out))
+            || (right.is_and_of_prev_bit(out) && left.is_carry_input(out))
+    }
+
+    fn is_carry_input(&self, out: &str) -> bool {
+        if self.left.is_none() || self.right.is_none() {
+            return false;
+        }
+
+        if self.val.gate != Gate::And {
+            return false;
+        }
+
+        let left = self.left.as_ref().unwrap();
+        let right = self.right.as_ref().unwrap();
+
+        let n = out[1..].parse::<usize>().unwrap();
+        let next_bit = format!("z{:02}", n - 1);
+
+        (left.is_xor_for_main_bits(&next_bit) && right.is_correct_carry_gates(&next_bit))
+            || (right.is_xor_for_main_bits(&next_bit) && left.is_correct_carry_gates(&next_bit))
+    }
+
+    fn is_and_of_prev_bit(&self, out: &str) -> bool {
+        let n = out[1..].parse::<usize>();
+        if n.is_err() {
+            return false;
+        }
+
+        let prev_bit = n.unwrap() - 1;
+        let needed_bits = [format!("x{:02}", prev_bit), format!("y{:02}", prev_bit)];
+
+        let mut actual_inputs = [self.val.a.clone(), self.val.b.clone()];
+        actual_inputs.sort();
+
+        self.val.gate == Gate::And && actual_inputs == needed_bits
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(left), Some(right)) = (&self.left, &self.right) {
+            write!(f, "({}){}({})", left, self.val.gate, right)
+        } else {
+            write!(f, "{}", self.val)
+        }
+    }
+}
+
+fn get_num_from_list_of_gates(gates: &mut Vec<(&String, &bool)>) -> usize {
+    gates.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut result = 0;
+
+    gates.iter().enumerate().for_each(|(i, (_, v))| {
+        if **v {
+            result |= 1 << i;
+        }
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let device = load_input::<Day24>(24, "example").expect("failed to read input");
+        assert_eq!(Day24::part1(&device), 2024);
+    }
+}
@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use common::{
+    params::NoParams,
+    parse::{self, ParseError, ParseErrorKind},
+    Solution,
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Schematic>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+    type Params = NoParams;
+
+    fn parse(input: &str, _: &NoParams) -> Result<Self::Input, anyhow::Error> {
+        Ok(parse::blocks(input)?)
+    }
+
+    fn part1(schematics: &Self::Input) -> usize {
+        let lock_cols: Vec<_> = schematics
+            .iter()
+            .filter_map(|sch| {
+                if let Schematic::Lock(cols) = sch {
+                    Some(cols)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let key_cols: Vec<_> = schematics
+            .iter()
+            .filter_map(|sch| {
+                if let Schematic::Key(cols) = sch {
+                    Some(cols)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let mut valid_combos = 0;
+
+        lock_cols.iter().for_each(|lock| {
+            key_cols.iter().for_each(|key| {
+                if key_and_lock_fit(key, lock) {
+                    valid_combos += 1;
+                }
+            });
+        });
+
+        valid_combos
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        // there's no puzzle for part 2 on christmas
+        "Merry Christmas!"
+    }
+}
+
+pub enum Schematic {
+    Key(Vec<usize>),
+    Lock(Vec<usize>),
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lines = parse::char_grid(value, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseErrorKind::UnexpectedChar(c)),
+        })?;
+        if lines.len() < 2 {
+            return Err(ParseError::end_of(value, value, "another row"));
+        }
+        let mut cols = vec![0; lines[0].len()];
+        if lines[0].iter().all(|filled| *filled) {
+            // Lock
+            lines[1..].iter().for_each(|line| {
+                line.iter().enumerate().for_each(|(i, filled)| {
+                    if *filled {
+                        cols[i] += 1;
+                    }
+                });
+            });
+            Ok(Schematic::Lock(cols))
+        } else {
+            // Key
+            lines[0..lines.len() - 1].iter().rev().for_each(|line| {
+                line.iter().enumerate().for_each(|(i, filled)| {
+                    if *filled {
+                        cols[i] += 1;
+                    }
+                });
+            });
+            Ok(Schematic::Key(cols))
+        }
+    }
+}
+
+fn key_and_lock_fit(key: &[usize], lock: &[usize]) -> bool {
+    key.iter()
+        .enumerate()
+        .all(|(i, key_col_height)| (key_col_height + lock[i]) <= 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::load_input;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = load_input::<Day25>(25, "example").expect("failed to get input");
+        assert_eq!(Day25::part1(&input), 3);
+    }
+
+    #[test]
+    fn can_read_input() {
+        let input = load_input::<Day25>(25, "example").expect("failed to get input");
+
+        assert!(matches!(input[0], Schematic::Lock(_)));
+        let Schematic::Lock(cols) = &input[0] else {
+            panic!();
+        };
+        assert_eq!(cols, &vec![0, 5, 3, 4, 3]);
+
+        assert!(matches!(input[2], Schematic::Key(_)));
+        let Schematic::Key(cols) = &input[2] else {
+            panic!();
+        };
+        assert_eq!(cols, &vec![5, 0, 2, 1, 3]);
+    }
+}
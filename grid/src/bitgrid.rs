@@ -0,0 +1,340 @@
+//! Grids of single bits, for keeping track of which spots have been seen or are taken without
+//! hashing points in hot loops.
+
+use std::ops::Index;
+
+use crate::{grid, Direction, Grid, Point};
+
+/// A set of points on a `width` by `height` grid, one bit per spot. Out of bounds points are
+/// never in the set, and inserting one panics.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    bits: Bits,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: Bits::new(width * height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    /// Whether `p` is set.
+    pub fn get(&self, p: Point) -> bool {
+        self.in_bounds(p) && self.bits.get(self.index(p))
+    }
+
+    /// Sets `p`, returning whether it wasn't set before.
+    pub fn insert(&mut self, p: Point) -> bool {
+        let i = self.checked_index(p);
+        self.bits.insert(i)
+    }
+
+    /// Clears `p`, returning whether it was set.
+    pub fn remove(&mut self, p: Point) -> bool {
+        self.in_bounds(p) && self.bits.remove(self.index(p))
+    }
+
+    pub fn set(&mut self, p: Point, on: bool) {
+        if on {
+            self.insert(p);
+        } else {
+            self.remove(p);
+        }
+    }
+
+    /// Clears every point, keeping the size.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// How many points are set.
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Every set point, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.bits.ones().map(|i| Point {
+            x: i % self.width,
+            y: i / self.width,
+        })
+    }
+
+    /// The in bounds neighbors of `p`, set or not.
+    pub fn neighbors(&self, p: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        grid::neighbors_within(self.width, self.height, p)
+    }
+
+    fn index(&self, p: Point) -> usize {
+        p.y * self.width + p.x
+    }
+
+    fn checked_index(&self, p: Point) -> usize {
+        assert!(
+            self.in_bounds(p),
+            "{p} is outside of a {}x{} grid",
+            self.width,
+            self.height
+        );
+        self.index(p)
+    }
+}
+
+impl Index<Point> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, p: Point) -> &bool {
+        if self.get(p) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        grid.iter().filter(|(_, on)| **on).for_each(|(p, _)| {
+            bits.insert(p);
+        });
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid::from_fn(bits.width, bits.height, |p| bits.get(p))
+    }
+}
+
+/// A set of points paired with the way something was facing there, like the states a guard
+/// walking around has been in. It's a [`BitGrid`] with four bits per spot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirectedBitGrid {
+    width: usize,
+    height: usize,
+    bits: Bits,
+}
+
+impl DirectedBitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: Bits::new(width * height * 4),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point, dir: Direction) -> bool {
+        self.in_bounds(p) && self.bits.get(self.index(p, dir))
+    }
+
+    /// Sets `p` facing `dir`, returning whether it wasn't set before.
+    pub fn insert(&mut self, p: Point, dir: Direction) -> bool {
+        assert!(
+            self.in_bounds(p),
+            "{p} is outside of a {}x{} grid",
+            self.width,
+            self.height
+        );
+        self.bits.insert(self.index(p, dir))
+    }
+
+    /// Clears `p` facing `dir`, returning whether it was set.
+    pub fn remove(&mut self, p: Point, dir: Direction) -> bool {
+        self.in_bounds(p) && self.bits.remove(self.index(p, dir))
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Every set point and direction, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, Direction)> + '_ {
+        self.bits.ones().map(move |i| {
+            let (spot, dir) = (i / 4, i % 4);
+            let p = Point {
+                x: spot % self.width,
+                y: spot / self.width,
+            };
+            (
+                p,
+                Direction::from_index(dir).expect("there are 4 directions"),
+            )
+        })
+    }
+
+    /// Every point that's set facing at least one way.
+    pub fn points(&self) -> BitGrid {
+        let mut points = BitGrid::new(self.width, self.height);
+        self.iter().for_each(|(p, _)| {
+            points.insert(p);
+        });
+        points
+    }
+
+    fn index(&self, p: Point, dir: Direction) -> usize {
+        (p.y * self.width + p.x) * 4 + dir.index()
+    }
+}
+
+/// A fixed number of bits packed into words.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn insert(&mut self, i: usize) -> bool {
+        let was_set = self.get(i);
+        self.words[i / 64] |= 1 << (i % 64);
+        !was_set
+    }
+
+    fn remove(&mut self, i: usize) -> bool {
+        let was_set = self.get(i);
+        self.words[i / 64] &= !(1 << (i % 64));
+        was_set
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The index of every set bit, lowest first.
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(w * 64 + bit)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove_report_changes() {
+        let mut bits = BitGrid::new(10, 10);
+        let p = Point { x: 3, y: 7 };
+
+        assert!(bits.insert(p));
+        assert!(!bits.insert(p));
+        assert!(bits[p]);
+        assert_eq!(bits.count(), 1);
+
+        assert!(bits.remove(p));
+        assert!(!bits.remove(p));
+        assert!(bits.is_empty());
+        assert!(!bits.get(Point { x: 10, y: 0 }));
+    }
+
+    #[test]
+    fn iter_goes_in_row_major_order_across_words() {
+        let mut bits = BitGrid::new(9, 9);
+        let points = [
+            Point { x: 8, y: 0 },
+            Point { x: 0, y: 7 },
+            Point { x: 1, y: 7 },
+            Point { x: 8, y: 8 },
+        ];
+        points.iter().rev().for_each(|p| {
+            bits.insert(*p);
+        });
+
+        assert_eq!(bits.iter().collect::<Vec<_>>(), points);
+        bits.clear();
+        assert_eq!(bits.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside")]
+    fn inserting_out_of_bounds_panics() {
+        BitGrid::new(2, 2).insert(Point { x: 2, y: 0 });
+    }
+
+    #[test]
+    fn directed_bits_keep_directions_apart() {
+        let mut seen = DirectedBitGrid::new(5, 5);
+        let p = Point { x: 4, y: 4 };
+
+        assert!(seen.insert(p, Direction::Left));
+        assert!(seen.insert(p, Direction::Up));
+        assert!(!seen.insert(p, Direction::Left));
+        assert!(!seen.get(p, Direction::Right));
+
+        assert_eq!(seen.count(), 2);
+        assert_eq!(
+            seen.iter().collect::<Vec<_>>(),
+            [(p, Direction::Up), (p, Direction::Left)]
+        );
+        assert_eq!(seen.points().count(), 1);
+    }
+}
@@ -0,0 +1,363 @@
+use std::ops::{Index, IndexMut};
+
+use common::parse::{self, ParseError, ParseErrorKind};
+use strum::IntoEnumIterator;
+
+use crate::{Direction, Direction8, Point, Vec2};
+
+/// A rectangular grid stored row-major in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |_| fill.clone())
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let (width, height) = if width == 0 || height == 0 {
+            (0, 0)
+        } else {
+            (width, height)
+        };
+
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point { x, y }))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid out of its rows, or `None` if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        if width == 0 {
+            return Some(Self {
+                width: 0,
+                height: 0,
+                cells: Vec::new(),
+            });
+        }
+
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    /// Turns signed coordinates, like the ones from `Point::add_direction`, into a point if
+    /// they're inside the grid.
+    pub fn checked_point(&self, v: Vec2) -> Option<Point> {
+        Point::try_from(v).ok().filter(|p| self.contains(*p))
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[self.idx(p)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            let idx = self.idx(p);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    /// The point one step from `p` in `dir`, if it's still inside the grid.
+    pub fn neighbor(&self, p: Point, dir: Direction) -> Option<Point> {
+        self.checked_point(p.add_direction(&dir))
+    }
+
+    /// Every neighbor of `p` that's inside the grid, along with the direction to it.
+    pub fn neighbors(&self, p: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        neighbors_within(self.width, self.height, p)
+    }
+
+    /// Like [`Grid::neighbor`], but diagonals are allowed too.
+    pub fn neighbor8(&self, p: Point, dir: Direction8) -> Option<Point> {
+        self.checked_point(p.add_direction8(&dir))
+    }
+
+    /// Like [`Grid::neighbors`], but including the diagonal neighbors.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Direction8, Point)> + '_ {
+        Direction8::iter().filter_map(move |dir| self.neighbor8(p, dir).map(|n| (dir, n)))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every point in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point {
+            x: i % width,
+            y: i / width,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    /// The first point, in row-major order, whose value matches `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, val)| pred(val)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Parses a map one char at a time, turning each char into a cell with `f`. Blank lines are
+    /// skipped, and every row has to be as wide as the first one.
+    pub fn parse(
+        input: &str,
+        f: impl FnMut(char) -> Result<T, ParseErrorKind>,
+    ) -> Result<Self, ParseError> {
+        let rows = parse::char_grid(input, f)?;
+        Ok(Self::from_rows(rows).expect("char_grid should only return rows of the same width"))
+    }
+
+    /// Like [`Grid::parse`], but also finds where each of `markers` is, like the `S` and `E` of a
+    /// maze. `f` still gets the marker chars, so it decides what's underneath them. Every marker
+    /// has to show up exactly once.
+    pub fn parse_with_markers<const N: usize>(
+        input: &str,
+        markers: [char; N],
+        f: impl FnMut(char) -> Result<T, ParseErrorKind>,
+    ) -> Result<(Self, [Point; N]), ParseError> {
+        let grid = Self::parse(input, f)?;
+
+        let mut found = [None; N];
+        let rows = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        for (y, (line, row)) in rows.enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                let Some(i) = markers.iter().position(|marker| *marker == c) else {
+                    continue;
+                };
+                if found[i].replace(Point { x, y }).is_some() {
+                    return Err(ParseError::new(
+                        line + 1,
+                        x + 1,
+                        ParseErrorKind::Expected(format!("only one {c:?}")),
+                    ));
+                }
+            }
+        }
+
+        if let Some(i) = found.iter().position(Option::is_none) {
+            return Err(ParseError::end_of(
+                input,
+                input,
+                &format!("{:?}", markers[i]),
+            ));
+        }
+
+        Ok((
+            grid,
+            found.map(|p| p.expect("every marker should be found")),
+        ))
+    }
+
+    /// Points a parse error at `p`, for a grid that was parsed from `input` with [`Grid::parse`].
+    /// For cells that are fine on their own but not where they are, like a second start.
+    pub fn parse_error_at(&self, input: &str, p: Point, kind: ParseErrorKind) -> ParseError {
+        let (line, _) = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .nth(p.y)
+            .unwrap_or_else(|| panic!("{p} isn't in the grid parsed from the input"));
+        ParseError::new(line + 1, p.x + 1, kind)
+    }
+
+    fn idx(&self, p: Point) -> usize {
+        p.y * self.width + p.x
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p} is outside of the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// The neighbors of `p` in a `width` by `height` area, for anything laid out like a grid.
+pub(crate) fn neighbors_within(
+    width: usize,
+    height: usize,
+    p: Point,
+) -> impl Iterator<Item = (Direction, Point)> {
+    Direction::iter().filter_map(move |dir| {
+        Point::try_from(p.add_direction(&dir))
+            .ok()
+            .filter(|n| n.x < width && n.y < height)
+            .map(|n| (dir, n))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn from_rows_rejects_ragged_rows() {
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[test]
+    fn indexing_works() {
+        let mut grid = example();
+        assert_eq!(grid[Point { x: 2, y: 1 }], 6);
+        assert_eq!(grid.get(Point { x: 3, y: 0 }), None);
+
+        grid[Point { x: 0, y: 1 }] = 9;
+        assert_eq!(grid.row(1), Some(&[9, 5, 6][..]));
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = example();
+        let neighbors: Vec<_> = grid.neighbors(Point { x: 0, y: 0 }).collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Direction::Down, Point { x: 0, y: 1 }),
+                (Direction::Right, Point { x: 1, y: 0 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors8_include_diagonals() {
+        let grid = example();
+        let neighbors: Vec<_> = grid.neighbors8(Point { x: 2, y: 0 }).collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Direction8::Down, Point { x: 2, y: 1 }),
+                (Direction8::DownLeft, Point { x: 1, y: 1 }),
+                (Direction8::Left, Point { x: 1, y: 0 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn columns_work() {
+        let grid = example();
+        let cols: Vec<Vec<_>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(cols, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn parse_finds_markers() {
+        let (grid, [start, end]) =
+            Grid::parse_with_markers("#S.\n\n.E#\n", ['S', 'E'], |c| Ok(c == '#')).unwrap();
+        assert_eq!(grid.height(), 2);
+        assert_eq!(start, Point { x: 1, y: 0 });
+        assert_eq!(end, Point { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn parse_rejects_missing_and_repeated_markers() {
+        let err = Grid::parse_with_markers("S.\n..", ['S', 'E'], Ok).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 3, ParseErrorKind::UnexpectedEnd("'E'".to_string()))
+        );
+
+        let err = Grid::parse_with_markers("S.\n.S", ['S'], Ok).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 2, ParseErrorKind::Expected("only one 'S'".to_string()))
+        );
+    }
+
+    #[test]
+    fn empty_grid_has_no_points() {
+        let grid: Grid<u8> = Grid::from_rows(vec![]).unwrap();
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.checked_point(Vec2::ZERO), None);
+    }
+}
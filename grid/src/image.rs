@@ -0,0 +1,350 @@
+//! Saving grids as pictures, for when a terminal dump is too big to make sense of. Images are
+//! written as PPM or PNG without pulling in any image crates, so the PNGs aren't compressed at all.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{Color, Grid};
+
+/// The colour of one pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Red => Rgb(220, 50, 47),
+            Color::Green => Rgb(80, 200, 80),
+            Color::Yellow => Rgb(230, 200, 40),
+            Color::Blue => Rgb(60, 120, 230),
+            Color::Magenta => Rgb(210, 60, 200),
+            Color::Cyan => Rgb(40, 200, 210),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+
+    /// Picks the format from a file's extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+/// A picture of a grid, one pixel per cell until it gets scaled up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    /// A picture of the grid, where `color` picks the colour of each cell.
+    pub fn to_image(&self, color: impl Fn(&T) -> Rgb) -> Image {
+        Image {
+            width: self.width(),
+            height: self.height(),
+            pixels: self.rows().flatten().map(color).collect(),
+        }
+    }
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Blows every pixel up into a `factor` by `factor` square, since one pixel per cell is
+    /// usually too small to see.
+    pub fn scale(&self, factor: usize) -> Image {
+        let pixels = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|row| {
+                let row: Vec<_> = row
+                    .iter()
+                    .flat_map(|pixel| std::iter::repeat_n(*pixel, factor))
+                    .collect();
+                std::iter::repeat_n(row, factor).flatten()
+            })
+            .collect();
+
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    pub fn write(&self, format: ImageFormat, out: impl Write) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+        }
+    }
+
+    /// Saves the image in whichever format the path's extension asks for.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} should end in .ppm or .png", path.display()),
+            )
+        })?;
+
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(format, &mut out)?;
+        out.flush()
+    }
+
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.rgb_bytes())
+    }
+
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        let too_big = |len: usize| {
+            u32::try_from(len).map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidInput, "image is too big for a png")
+            })
+        };
+
+        let mut header = Vec::with_capacity(13);
+        header.extend(too_big(self.width)?.to_be_bytes());
+        header.extend(too_big(self.height)?.to_be_bytes());
+        // 8 bits per channel, plain rgb, and the only compression, filter and interlace methods
+        // there are
+        header.extend([8, 2, 0, 0, 0]);
+
+        // every row starts with which filter it uses, and these don't use one
+        let rows: Vec<u8> = self
+            .rgb_bytes()
+            .chunks(self.width.max(1) * 3)
+            .flat_map(|row| std::iter::once(0).chain(row.iter().copied()))
+            .collect();
+
+        out.write_all(b"\x89PNG\r\n\x1a\n")?;
+        write_png_chunk(&mut out, b"IHDR", &header)?;
+        write_png_chunk(&mut out, b"IDAT", &zlib_stored(&rows))?;
+        write_png_chunk(&mut out, b"IEND", &[])
+    }
+
+    fn rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+            .collect()
+    }
+}
+
+/// Writes numbered images into a directory, one per step of a simulation, so they can be flipped
+/// through or stitched into a gif afterwards.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    written: usize,
+}
+
+impl Frames {
+    /// Starts writing PNG frames into `dir`, creating it if it isn't there yet.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            format: ImageFormat::Png,
+            scale: 1,
+            written: 0,
+        })
+    }
+
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Scales every frame up by `factor` before writing it.
+    pub fn scale(mut self, factor: usize) -> Self {
+        self.scale = factor;
+        self
+    }
+
+    /// Writes the next frame and returns where it went.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.written,
+            self.format.extension()
+        ));
+
+        let mut out = BufWriter::new(File::create(&path)?);
+        if self.scale == 1 {
+            image.write(self.format, &mut out)?;
+        } else {
+            image.scale(self.scale).write(self.format, &mut out)?;
+        }
+        out.flush()?;
+
+        self.written += 1;
+        Ok(path)
+    }
+
+    /// How many frames have been written so far.
+    pub fn len(&self) -> usize {
+        self.written
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.written == 0
+    }
+}
+
+fn write_png_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "png chunk is too big"))?;
+
+    out.write_all(&len.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    out.write_all(&crc32(kind.iter().chain(data)).to_be_bytes())
+}
+
+/// Wraps `data` up as a zlib stream without compressing it, using deflate's stored blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        out.push(is_last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+
+    out
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    !bytes.into_iter().fold(!0, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), byte| {
+        let a = (a + *byte as u32) % MOD;
+        (a, (b + a) % MOD)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Image {
+        Grid::parse("#.\n.#", |c| Ok(c == '#'))
+            .unwrap()
+            .to_image(|wall| if *wall { Rgb::WHITE } else { Rgb::BLACK })
+    }
+
+    #[test]
+    fn checksums_match_known_values() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn ppm_is_a_header_then_raw_pixels() {
+        let mut out = Vec::new();
+        example().write_ppm(&mut out).unwrap();
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn png_has_its_signature_and_chunks() {
+        let mut out = Vec::new();
+        example().scale(3).write_png(&mut out).unwrap();
+
+        assert!(out.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x06\0\0\0\x06"));
+        assert!(out.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+
+    #[test]
+    fn stored_blocks_split_big_data() {
+        let data = vec![7; 70_000];
+        let zlib = zlib_stored(&data);
+
+        // two block headers of five bytes, plus the zlib header and checksum
+        assert_eq!(zlib.len(), data.len() + 2 * 5 + 2 + 4);
+        assert_eq!(zlib[2], 0);
+        assert_eq!(zlib[2 + 5 + u16::MAX as usize], 1);
+    }
+
+    #[test]
+    fn frames_are_numbered_in_order() {
+        let dir = std::env::temp_dir().join(format!("grid-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir).unwrap().format(ImageFormat::Ppm);
+
+        let first = frames.push(&example()).unwrap();
+        let second = frames.push(&example()).unwrap();
+
+        assert_eq!(first.file_name().unwrap(), "frame_00000.ppm");
+        assert_eq!(second.file_name().unwrap(), "frame_00001.ppm");
+        assert_eq!(frames.len(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
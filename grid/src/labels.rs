@@ -0,0 +1,139 @@
+use crate::{Grid, Point};
+
+/// Every cell of a grid split up into connected regions, made by [`Grid::label_regions`].
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Vec<Point>>,
+}
+
+impl Regions {
+    /// Which region `p` is in, as an index into the regions.
+    pub fn label(&self, p: Point) -> Option<usize> {
+        self.labels.get(p).copied()
+    }
+
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// The cells in region `label`, in the order the flood fill found them.
+    pub fn cells(&self, label: usize) -> &[Point] {
+        &self.regions[label]
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[Point]> {
+        self.regions.iter().map(Vec::as_slice)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every point that can be reached from `start` by stepping between neighbors where
+    /// `connected(from, to)` holds, including `start` itself. It works off of a stack rather than
+    /// recursion, so big regions can't overflow the stack.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        mut connected: impl FnMut(&T, &T) -> bool,
+    ) -> Vec<Point> {
+        let mut seen = Grid::new(self.width(), self.height(), false);
+        self.fill_unseen(start, &mut seen, &mut connected)
+    }
+
+    /// Splits the whole grid up into regions of cells that are connected by `same_region`, which
+    /// should be symmetric, like checking that two cells hold the same plant.
+    pub fn label_regions(&self, mut same_region: impl FnMut(&T, &T) -> bool) -> Regions {
+        let mut seen = Grid::new(self.width(), self.height(), false);
+        let mut labels = Grid::new(self.width(), self.height(), 0);
+        let mut regions = Vec::new();
+
+        for p in self.points() {
+            if seen[p] {
+                continue;
+            }
+
+            let region = self.fill_unseen(p, &mut seen, &mut same_region);
+            region.iter().for_each(|cell| labels[*cell] = regions.len());
+            regions.push(region);
+        }
+
+        Regions { labels, regions }
+    }
+
+    fn fill_unseen(
+        &self,
+        start: Point,
+        seen: &mut Grid<bool>,
+        connected: &mut impl FnMut(&T, &T) -> bool,
+    ) -> Vec<Point> {
+        if !self.contains(start) {
+            return Vec::new();
+        }
+
+        let mut filled = Vec::new();
+        let mut stack = vec![start];
+        seen[start] = true;
+
+        while let Some(p) = stack.pop() {
+            filled.push(p);
+            for (_, n) in self.neighbors(p) {
+                if !seen[n] && connected(&self[p], &self[n]) {
+                    seen[n] = true;
+                    stack.push(n);
+                }
+            }
+        }
+
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("AAB\nABB\nCAA", Ok).unwrap()
+    }
+
+    #[test]
+    fn label_regions_splits_up_matching_cells() {
+        let grid = example();
+        let regions = grid.label_regions(|a, b| a == b);
+
+        assert_eq!(regions.len(), 4);
+        assert_eq!(regions.label(Point { x: 0, y: 1 }), Some(0));
+        assert_eq!(regions.cells(0).len(), 3);
+        assert_ne!(
+            regions.label(Point { x: 1, y: 2 }),
+            regions.label(Point { x: 0, y: 0 })
+        );
+        assert_eq!(regions.iter().map(<[Point]>::len).sum::<usize>(), 9);
+    }
+
+    #[test]
+    fn flood_fill_can_go_one_way() {
+        let grid = Grid::parse("0123\n1234", |c| Ok(c.to_digit(10).unwrap())).unwrap();
+        let uphill = grid.flood_fill(Point { x: 1, y: 1 }, |from, to| *to == from + 1);
+
+        assert_eq!(uphill.len(), 3);
+        assert!(uphill.contains(&Point { x: 3, y: 1 }));
+    }
+
+    #[test]
+    fn flood_fill_handles_huge_regions() {
+        let grid = Grid::new(1000, 1000, ());
+        assert_eq!(
+            grid.flood_fill(Point { x: 0, y: 0 }, |_, _| true).len(),
+            1_000_000
+        );
+    }
+}
@@ -1,5 +1,28 @@
+use std::fmt::Display;
+
+use common::parse::ParseErrorKind;
 use strum::EnumIter;
 
+mod bitgrid;
+mod grid;
+mod image;
+mod labels;
+mod region;
+mod render;
+pub mod search;
+mod torus;
+mod vec2;
+pub mod walker;
+
+pub use bitgrid::{BitGrid, DirectedBitGrid};
+pub use grid::Grid;
+pub use image::{Frames, Image, ImageFormat, Rgb};
+pub use labels::Regions;
+pub use region::{Edge, Region};
+pub use render::{Color, Render};
+pub use torus::{Quadrant, Torus};
+pub use vec2::Vec2;
+
 #[derive(Debug, EnumIter, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
 pub enum Direction {
     Up,
@@ -9,12 +32,13 @@ pub enum Direction {
 }
 
 impl Direction {
-    fn translate(&self, x: i64, y: i64) -> (i64, i64) {
+    /// How far one step in this direction moves along x and y.
+    pub fn offset(&self) -> (i64, i64) {
         match self {
-            Direction::Up => (x, y - 1),
-            Direction::Down => (x, y + 1),
-            Direction::Left => (x - 1, y),
-            Direction::Right => (x + 1, y),
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
         }
     }
 
@@ -27,39 +51,211 @@ impl Direction {
         }
     }
 
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Straight => *self,
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Back => self.opposite(),
+        }
+    }
+
+    /// The turn that takes this direction to `other`.
+    pub fn turn_to(&self, other: &Direction) -> Turn {
+        if self == other {
+            Turn::Straight
+        } else if self.turn_left() == *other {
+            Turn::Left
+        } else if self.turn_right() == *other {
+            Turn::Right
+        } else {
+            Turn::Back
+        }
+    }
+
     pub fn turns_to_other_dir(&self, other: &Direction) -> u64 {
+        self.turn_to(other).quarter_turns()
+    }
+
+    /// A different number from 0 to 3 for each direction, for packing them into arrays or bits.
+    pub fn index(&self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+        }
+    }
+
+    /// The direction with the given [`Direction::index`].
+    pub fn from_index(i: usize) -> Option<Self> {
+        match i {
+            0 => Some(Direction::Up),
+            1 => Some(Direction::Down),
+            2 => Some(Direction::Left),
+            3 => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Parses one of `^v<>`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+/// Parses an arrow (`^v<>`), a letter (`UDLR`) or a compass point (`NSEW`, with north being up).
+impl TryFrom<char> for Direction {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' => Ok(Direction::Up),
+            'D' | 'S' => Ok(Direction::Down),
+            'L' | 'W' => Ok(Direction::Left),
+            'R' | 'E' => Ok(Direction::Right),
+            _ => Direction::from_arrow(c).ok_or(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+}
+
+/// A turn relative to the direction something is already facing.
+#[derive(Debug, EnumIter, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+    Back,
+}
+
+impl Turn {
+    /// How many 90 degree turns this takes.
+    pub fn quarter_turns(&self) -> u64 {
         match self {
-            Direction::Up => match other {
-                Direction::Up => 0,
-                Direction::Down => 2,
-                Direction::Left => 1,
-                Direction::Right => 1,
-            },
-            Direction::Down => match other {
-                Direction::Up => 2,
-                Direction::Down => 0,
-                Direction::Left => 1,
-                Direction::Right => 1,
-            },
-            Direction::Left => match other {
-                Direction::Up => 1,
-                Direction::Down => 1,
-                Direction::Left => 0,
-                Direction::Right => 2,
-            },
-            Direction::Right => match other {
-                Direction::Up => 1,
-                Direction::Down => 1,
-                Direction::Left => 2,
-                Direction::Right => 0,
-            },
+            Turn::Straight => 0,
+            Turn::Left | Turn::Right => 1,
+            Turn::Back => 2,
         }
     }
 }
 
-pub struct Velocity {
-    pub x_vel: i64,
-    pub y_vel: i64,
+/// A direction that can also be diagonal, listed clockwise starting from `Up`.
+#[derive(Debug, EnumIter, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub fn translate(&self, x: i64, y: i64) -> (i64, i64) {
+        let (dx, dy) = self.offset();
+        (x + dx, y + dy)
+    }
+
+    /// How far one step in this direction moves along x and y.
+    pub fn offset(&self) -> (i64, i64) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn rotate_45(&self) -> Self {
+        match self {
+            Direction8::Up => Direction8::UpRight,
+            Direction8::UpRight => Direction8::Right,
+            Direction8::Right => Direction8::DownRight,
+            Direction8::DownRight => Direction8::Down,
+            Direction8::Down => Direction8::DownLeft,
+            Direction8::DownLeft => Direction8::Left,
+            Direction8::Left => Direction8::UpLeft,
+            Direction8::UpLeft => Direction8::Up,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate_45().rotate_45().rotate_45().rotate_45()
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.cardinal().is_none()
+    }
+
+    pub fn cardinal(&self) -> Option<Direction> {
+        match self {
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::Left => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The vertical and horizontal directions a diagonal is made of, like `(Up, Left)` for
+    /// `UpLeft`.
+    pub fn split_diagonal(&self) -> Option<(Direction, Direction)> {
+        match self {
+            Direction8::UpRight => Some((Direction::Up, Direction::Right)),
+            Direction8::DownRight => Some((Direction::Down, Direction::Right)),
+            Direction8::DownLeft => Some((Direction::Down, Direction::Left)),
+            Direction8::UpLeft => Some((Direction::Up, Direction::Left)),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
@@ -69,25 +265,19 @@ pub struct Point {
 }
 
 impl Point {
-    pub fn new(x: i64, y: i64) -> Self {
-        Self {
-            x: x as usize,
-            y: y as usize,
-        }
+    /// The point as a [`Vec2`], panicking if it's too big to fit. That can't happen for a point in
+    /// a grid, so use [`Vec2::try_from`] only for points from somewhere else.
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::try_from(self).expect("point is too big for a Vec2")
     }
 
-    pub fn add_direction(&self, dir: &Direction) -> (i64, i64) {
-        let x = self.x as i64;
-        let y = self.y as i64;
-        dir.translate(x, y)
+    /// The spot one step away in `dir`, which might be off the grid.
+    pub fn add_direction(&self, dir: &Direction) -> Vec2 {
+        self.to_vec2() + Vec2::from(*dir)
     }
 
-    pub fn add_diagonal_direction(&self, dir: (&Direction, &Direction)) -> (i64, i64) {
-        let mut x = self.x as i64;
-        let mut y = self.y as i64;
-
-        (x, y) = dir.0.translate(x, y);
-        dir.1.translate(x, y)
+    pub fn add_direction8(&self, dir: &Direction8) -> Vec2 {
+        self.to_vec2() + Vec2::from(*dir)
     }
 
     pub fn direction_to_point(&self, other: &Point) -> Direction {
@@ -122,10 +312,12 @@ impl Point {
 
         let mut points = Vec::new();
 
-        let mut cur = self.clone();
+        let mut cur = *self;
         loop {
-            let (x, y) = cur.add_direction(&dir);
-            cur = Point::new(x, y);
+            cur = cur
+                .add_direction(&dir)
+                .try_into()
+                .expect("should be heading towards other");
 
             if cur == *other {
                 break;
@@ -138,10 +330,66 @@ impl Point {
     }
 }
 
-pub fn new_point_if_in_bounds<T>(grid: &Vec<Vec<T>>, x: i64, y: i64) -> Option<Point> {
-    if x < 0 || y < 0 || x >= grid[0].len() as i64 || y >= grid.len() as i64 {
-        None
-    } else {
-        Some(Point::new(x, y))
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn rotate_45_goes_clockwise_through_every_direction() {
+        let rotated: Vec<_> = Direction8::iter().map(|dir| dir.rotate_45()).collect();
+        let expected: Vec<_> = Direction8::iter().cycle().skip(1).take(8).collect();
+        assert_eq!(rotated, expected);
+        assert_eq!(Direction8::UpLeft.opposite(), Direction8::DownRight);
+    }
+
+    #[test]
+    fn turns_are_relative_to_the_current_direction() {
+        for dir in Direction::iter() {
+            for turn in Turn::iter() {
+                assert_eq!(dir.turn_to(&dir.turn(turn)), turn);
+            }
+            assert_eq!(dir.turn_left().turn_left(), dir.opposite());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn direction_indexes_go_both_ways() {
+        for dir in Direction::iter() {
+            assert_eq!(Direction::from_index(dir.index()), Some(dir));
+        }
+        assert_eq!(Direction::from_index(4), None);
+    }
+
+    #[test]
+    fn direction_parses_every_notation() {
+        let parsed: Vec<_> = "^UN".chars().map(Direction::try_from).collect();
+        assert_eq!(parsed, vec![Ok(Direction::Up); 3]);
+        assert_eq!(Direction::try_from('>'), Ok(Direction::Right));
+        assert_eq!(Direction::try_from('W'), Ok(Direction::Left));
+        assert_eq!(
+            Direction::try_from('x'),
+            Err(ParseErrorKind::UnexpectedChar('x'))
+        );
+    }
+
+    #[test]
+    fn direction8_splits_into_cardinals() {
+        for dir in Direction::iter() {
+            assert_eq!(Direction8::from(dir).cardinal(), Some(dir));
+        }
+        assert_eq!(
+            Direction8::DownLeft.split_diagonal(),
+            Some((Direction::Down, Direction::Left))
+        );
+        assert_eq!(Direction8::Left.split_diagonal(), None);
     }
 }
@@ -0,0 +1,253 @@
+use std::collections::{BTreeMap, HashSet};
+
+use strum::IntoEnumIterator;
+
+use crate::{Direction, Direction8, Grid, Point, Vec2};
+
+/// A set of cells, for asking questions about its shape like how much fence it would take to
+/// go around it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<Point>,
+}
+
+/// One straight piece of a region's outline. `from` and `to` are corners between cells, so the
+/// cell at `Point { x, y }` spans from corner `(x, y)` to corner `(x + 1, y + 1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Edge {
+    pub from: Point,
+    pub to: Point,
+    /// Which side of the region the edge is on, pointing away from the region.
+    pub facing: Direction,
+}
+
+impl Edge {
+    pub fn len(&self) -> usize {
+        self.from.x.abs_diff(self.to.x) + self.from.y.abs_diff(self.to.y)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Region {
+    pub fn new(cells: impl IntoIterator<Item = Point>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.cells.contains(p)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &Point> {
+        self.cells.iter()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// How many cell edges are on the border of the region, holes included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                Direction::iter()
+                    .filter(|dir| !self.contains_vec(cell.add_direction(dir)))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// How many straight sides the region has, holes included. Every side starts and ends at a
+    /// corner, so this counts corners.
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                Direction8::iter()
+                    .filter_map(|dir| dir.split_diagonal().map(|parts| (dir, parts)))
+                    .filter(|(diag, (vertical, horizontal))| {
+                        let vertical = self.contains_vec(cell.add_direction(vertical));
+                        let horizontal = self.contains_vec(cell.add_direction(horizontal));
+                        let diag = self.contains_vec(cell.add_direction8(diag));
+
+                        // either the outside of a corner, or the inside of one like
+                        // AA
+                        // AB
+                        (!vertical && !horizontal) || (vertical && horizontal && !diag)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The top left and bottom right cells of the smallest box the region fits in.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let min_x = self.cells.iter().map(|p| p.x).min()?;
+        let max_x = self.cells.iter().map(|p| p.x).max()?;
+        let min_y = self.cells.iter().map(|p| p.y).min()?;
+        let max_y = self.cells.iter().map(|p| p.y).max()?;
+
+        Some((Point { x: min_x, y: min_y }, Point { x: max_x, y: max_y }))
+    }
+
+    /// Every group of cells that's outside of the region but completely surrounded by it.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some((top_left, bottom_right)) = self.bounding_box() else {
+            return Vec::new();
+        };
+        let offset = |p: Point| Point {
+            x: p.x + top_left.x,
+            y: p.y + top_left.y,
+        };
+
+        let inside = Grid::from_fn(
+            bottom_right.x - top_left.x + 1,
+            bottom_right.y - top_left.y + 1,
+            |p| self.contains(&offset(p)),
+        );
+        let on_border = |p: &Point| {
+            p.x == 0 || p.y == 0 || p.x == inside.width() - 1 || p.y == inside.height() - 1
+        };
+
+        inside
+            .label_regions(|a, b| a == b)
+            .iter()
+            .filter(|cells| !inside[cells[0]] && !cells.iter().any(on_border))
+            .map(|cells| Region::new(cells.iter().map(|p| offset(*p))))
+            .collect()
+    }
+
+    /// The border of the region as straight edges, one for each side.
+    pub fn outline(&self) -> Vec<Edge> {
+        let mut edges = Vec::new();
+
+        for facing in Direction::iter() {
+            // fenced cells grouped by the line the fence is on, then by where along it they are
+            let mut lines: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+            self.cells
+                .iter()
+                .filter(|cell| !self.contains_vec(cell.add_direction(&facing)))
+                .for_each(|cell| match facing {
+                    Direction::Up | Direction::Down => {
+                        lines.entry(cell.y).or_default().push(cell.x)
+                    }
+                    Direction::Left | Direction::Right => {
+                        lines.entry(cell.x).or_default().push(cell.y)
+                    }
+                });
+
+            for (line, mut along) in lines {
+                along.sort_unstable();
+                let runs = along.chunk_by(|a, b| a + 1 == *b);
+                edges.extend(runs.map(|run| {
+                    let (start, end) = (run[0], run[run.len() - 1] + 1);
+                    let line = match facing {
+                        Direction::Up | Direction::Left => line,
+                        Direction::Down | Direction::Right => line + 1,
+                    };
+
+                    match facing {
+                        Direction::Up | Direction::Down => Edge {
+                            from: Point { x: start, y: line },
+                            to: Point { x: end, y: line },
+                            facing,
+                        },
+                        Direction::Left | Direction::Right => Edge {
+                            from: Point { x: line, y: start },
+                            to: Point { x: line, y: end },
+                            facing,
+                        },
+                    }
+                }));
+            }
+        }
+
+        edges
+    }
+
+    fn contains_vec(&self, v: Vec2) -> bool {
+        Point::try_from(v).is_ok_and(|p| self.contains(&p))
+    }
+}
+
+impl FromIterator<Point> for Region {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every cell marked `#` in a picture of the region.
+    fn region(picture: &str) -> Region {
+        let grid = Grid::parse(picture, |c| Ok(c == '#')).unwrap();
+        grid.iter()
+            .filter(|(_, in_region)| **in_region)
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    #[test]
+    fn square_geometry() {
+        let square = region("##\n##");
+        assert_eq!(square.area(), 4);
+        assert_eq!(square.perimeter(), 8);
+        assert_eq!(square.sides(), 4);
+        assert_eq!(
+            square.bounding_box(),
+            Some((Point { x: 0, y: 0 }, Point { x: 1, y: 1 }))
+        );
+        assert!(square.holes().is_empty());
+    }
+
+    #[test]
+    fn l_shape_has_six_sides() {
+        let l = region("#.\n#.\n##");
+        assert_eq!(l.perimeter(), 10);
+        assert_eq!(l.sides(), 6);
+    }
+
+    #[test]
+    fn holes_are_found_and_fenced() {
+        let ring = region(".....\n.###.\n.#.#.\n.###.\n.....");
+        assert_eq!(ring.area(), 8);
+        assert_eq!(ring.perimeter(), 16);
+        assert_eq!(ring.sides(), 8);
+        assert_eq!(ring.holes(), vec![Region::new([Point { x: 2, y: 2 }])]);
+    }
+
+    #[test]
+    fn gaps_to_the_outside_are_not_holes() {
+        let cup = region("#.#\n#.#\n###");
+        assert!(cup.holes().is_empty());
+    }
+
+    #[test]
+    fn outline_matches_sides_and_perimeter() {
+        let shape = region("###.\n#.##\n####\n.#..");
+        let outline = shape.outline();
+
+        assert_eq!(outline.len(), shape.sides());
+        assert_eq!(
+            outline.iter().map(Edge::len).sum::<usize>(),
+            shape.perimeter()
+        );
+        assert!(outline.contains(&Edge {
+            from: Point { x: 0, y: 0 },
+            to: Point { x: 3, y: 0 },
+            facing: Direction::Up,
+        }));
+        assert!(outline.contains(&Edge {
+            from: Point { x: 1, y: 4 },
+            to: Point { x: 2, y: 4 },
+            facing: Direction::Down,
+        }));
+    }
+}
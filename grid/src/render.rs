@@ -0,0 +1,203 @@
+//! Drawing grids in the terminal, with things like a path or a few interesting points drawn on
+//! top.
+
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{Grid, Point};
+
+/// Colours a renderer can draw with, using the basic ANSI escape codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// How one cell gets drawn over. `None` leaves that part of the cell alone.
+#[derive(Debug, Clone, Copy, Default)]
+struct Style {
+    symbol: Option<char>,
+    color: Option<Color>,
+    inverse: bool,
+}
+
+/// A grid on its way to being drawn, made by [`Grid::render`] or [`Grid::render_with`]. Overlays
+/// get drawn in the order they're added, so later ones win. Colours are only drawn after turning
+/// them on with [`Render::color`], so by default the output is plain text.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    to_char: Box<dyn Fn(&T) -> char + 'a>,
+    overlay: HashMap<Point, Style>,
+    color: bool,
+}
+
+impl<T> Grid<T> {
+    /// Draws every cell as the char it turns into.
+    pub fn render(&self) -> Render<'_, T>
+    where
+        T: Copy + Into<char>,
+    {
+        self.render_with(|cell| (*cell).into())
+    }
+
+    /// Draws every cell as whatever char `to_char` picks for it.
+    pub fn render_with<'a>(&'a self, to_char: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        Render {
+            grid: self,
+            to_char: Box::new(to_char),
+            overlay: HashMap::new(),
+            color: false,
+        }
+    }
+}
+
+impl<T> Render<'_, T> {
+    /// Turns ANSI colours on or off.
+    pub fn color(mut self, on: bool) -> Self {
+        self.color = on;
+        self
+    }
+
+    /// Colours `points` without changing what's drawn there, so this only shows up with colour
+    /// turned on.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Self {
+        for p in points {
+            self.overlay.entry(p).or_default().color = Some(color);
+        }
+        self
+    }
+
+    /// Draws `symbol` over each of `points`, in `color` if colour is on.
+    pub fn mark(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        symbol: char,
+        color: Color,
+    ) -> Self {
+        for p in points {
+            let style = self.overlay.entry(p).or_default();
+            style.symbol = Some(symbol);
+            style.color = Some(color);
+        }
+        self
+    }
+
+    /// Draws a path as arrows pointing the way it goes. Steps between points that aren't next to
+    /// each other, like the jumps in a compressed path, keep their spot's own char.
+    pub fn path(mut self, path: &[Point], color: Color) -> Self {
+        for step in path.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            let style = self.overlay.entry(from).or_default();
+            style.color = Some(color);
+            if from.x.abs_diff(to.x) + from.y.abs_diff(to.y) == 1 {
+                style.symbol = Some(from.direction_to_point(&to).arrow());
+            }
+        }
+        if let Some(last) = path.last() {
+            self.overlay.entry(*last).or_default().color = Some(color);
+        }
+        self
+    }
+
+    /// Draws `symbol` at `p`, and swaps the foreground and background there if colour is on.
+    pub fn cursor(mut self, p: Point, symbol: char) -> Self {
+        let style = self.overlay.entry(p).or_default();
+        style.symbol = Some(symbol);
+        style.inverse = true;
+        self
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, cell) in row.iter().enumerate() {
+                let style = self
+                    .overlay
+                    .get(&Point { x, y })
+                    .copied()
+                    .unwrap_or_default();
+                let c = style.symbol.unwrap_or_else(|| (self.to_char)(cell));
+
+                if !self.color || (style.color.is_none() && !style.inverse) {
+                    write!(f, "{c}")?;
+                    continue;
+                }
+
+                if let Some(color) = style.color {
+                    write!(f, "\x1b[{}m", color.ansi_code())?;
+                }
+                if style.inverse {
+                    write!(f, "\x1b[7m")?;
+                }
+                write!(f, "{c}\x1b[0m")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("#...\n#.#.\n....", Ok).unwrap()
+    }
+
+    #[test]
+    fn plain_render_matches_the_input() {
+        assert_eq!(example().render().to_string(), "#...\n#.#.\n....");
+    }
+
+    #[test]
+    fn overlays_draw_over_cells() {
+        let path = [
+            Point { x: 1, y: 0 },
+            Point { x: 1, y: 1 },
+            Point { x: 1, y: 2 },
+            Point { x: 2, y: 2 },
+        ];
+        let rendered = example()
+            .render()
+            .path(&path, Color::Green)
+            .mark([Point { x: 3, y: 0 }], 'X', Color::Red)
+            .cursor(Point { x: 0, y: 2 }, '@')
+            .to_string();
+
+        assert_eq!(rendered, "#v.X\n#v#.\n@>..");
+    }
+
+    #[test]
+    fn color_wraps_overlaid_cells_in_escape_codes() {
+        let rendered = example()
+            .render()
+            .highlight([Point { x: 1, y: 0 }], Color::Blue)
+            .color(true)
+            .to_string();
+
+        assert_eq!(rendered.lines().next(), Some("#\x1b[34m.\x1b[0m.."));
+        assert!(rendered.ends_with("...."));
+    }
+}
@@ -0,0 +1,501 @@
+//! Shortest path searches that work on any kind of node, not just grid points. The graph is
+//! given as a function from a node to its neighbors, so nothing has to be built up front.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest cost to every node a search reached, plus enough to rebuild the path to each.
+#[derive(Debug, Clone)]
+pub struct Distances<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    prev: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Distances<N, C> {
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The cheapest cost to get to `node`, or `None` if the search never reached it.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// One of the cheapest paths from the start to `node`, including both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        let mut cur = node;
+        while let Some(prev) = self.prev.get(cur) {
+            path.push(prev.clone());
+            cur = prev;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&N, &C)> {
+        self.costs.iter()
+    }
+
+    pub fn into_costs(self) -> HashMap<N, C> {
+        self.costs
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Distances<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut prev = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        let next_cost = costs[&node] + 1;
+        for next in neighbors(&node) {
+            if costs.contains_key(&next) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            prev.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+
+    Distances { start, costs, prev }
+}
+
+/// Dijkstra's algorithm over every node reachable from `start`. `neighbors` gives each neighbor
+/// along with the cost of stepping to it.
+pub fn dijkstra<N, C, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Distances<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = Distances {
+        start: start.clone(),
+        costs: HashMap::from([(start.clone(), C::default())]),
+        prev: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::from([MinScored(C::default(), start)]);
+
+    while let Some(MinScored(cost, node)) = heap.pop() {
+        if cost > distances.costs[&node] {
+            continue;
+        }
+        relax(
+            &mut distances,
+            &mut heap,
+            &node,
+            cost,
+            neighbors(&node),
+            |_| C::default(),
+        );
+    }
+
+    distances
+}
+
+/// A* search from `start` to the first node that `is_goal`, returning its cost and the path to it.
+/// `heuristic` must never overestimate the cost left to a goal, or the path might not be the
+/// cheapest. A heuristic that's always zero turns this into Dijkstra's algorithm that stops early.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = Distances {
+        start: start.clone(),
+        costs: HashMap::from([(start.clone(), C::default())]),
+        prev: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::from([MinScored(heuristic(&start), start)]);
+
+    while let Some(MinScored(estimate, node)) = heap.pop() {
+        let cost = distances.costs[&node];
+        if is_goal(&node) {
+            let path = distances.path_to(&node)?;
+            return Some((cost, path));
+        }
+        if estimate > cost + heuristic(&node) {
+            continue;
+        }
+        relax(
+            &mut distances,
+            &mut heap,
+            &node,
+            cost,
+            neighbors(&node),
+            &mut heuristic,
+        );
+    }
+
+    None
+}
+
+/// Every cheapest path from a start at once, stored as the predecessors each node has on those
+/// paths. Together they make a DAG, so paths can be counted or walked without ever listing them
+/// all out.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    prev: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The cheapest cost to get to `node`, or `None` if the search never reached it.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every node that comes right before `node` on one of the cheapest paths to it.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.prev.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// How many different cheapest paths there are from the start to `end`.
+    pub fn count_paths(&self, end: &N) -> u64 {
+        let Some((end, _)) = self.costs.get_key_value(end) else {
+            return 0;
+        };
+
+        // a node stays on the stack until everything before it has been counted, so long paths
+        // don't need a deep call stack
+        let mut counts: HashMap<&N, u64> = HashMap::from([(&self.start, 1)]);
+        let mut stack = vec![end];
+        while let Some(&node) = stack.last() {
+            if counts.contains_key(node) {
+                stack.pop();
+                continue;
+            }
+
+            let uncounted: Vec<_> = self
+                .predecessors(node)
+                .iter()
+                .filter(|prev| !counts.contains_key(prev))
+                .collect();
+            if uncounted.is_empty() {
+                let count = self
+                    .predecessors(node)
+                    .iter()
+                    .map(|prev| counts[prev])
+                    .sum();
+                counts.insert(node, count);
+                stack.pop();
+            } else {
+                stack.extend(uncounted);
+            }
+        }
+
+        counts[end]
+    }
+
+    /// Every node on any of the cheapest paths to any of `ends`.
+    pub fn nodes_on_paths<'a>(&self, ends: impl IntoIterator<Item = &'a N>) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let mut nodes = HashSet::new();
+        let mut to_visit: Vec<&N> = ends
+            .into_iter()
+            .filter(|end| self.costs.contains_key(*end))
+            .collect();
+
+        while let Some(node) = to_visit.pop() {
+            if nodes.insert(node.clone()) {
+                to_visit.extend(self.predecessors(node));
+            }
+        }
+
+        nodes
+    }
+
+    /// Walks every cheapest path from the start to `end` one at a time, each from start to end.
+    pub fn paths(&self, end: &N) -> Paths<'_, N, C> {
+        let stack = self
+            .costs
+            .get_key_value(end)
+            .map(|(end, _)| vec![(end, 0)])
+            .unwrap_or_default();
+
+        Paths { paths: self, stack }
+    }
+}
+
+/// Iterator over the paths in [`ShortestPaths`], made by [`ShortestPaths::paths`].
+pub struct Paths<'a, N, C> {
+    paths: &'a ShortestPaths<N, C>,
+    /// The path being built, going backwards from the end, with the index of the next predecessor
+    /// to try at each node.
+    stack: Vec<(&'a N, usize)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Iterator for Paths<'_, N, C> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, next_prev) = self.stack.last_mut()?;
+
+            if **node == self.paths.start {
+                let path = self.stack.iter().rev().map(|(n, _)| (*n).clone()).collect();
+                self.stack.pop();
+                return Some(path);
+            }
+
+            match self.paths.predecessors(node).get(*next_prev) {
+                Some(prev) => {
+                    *next_prev += 1;
+                    self.stack.push((prev, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Like [`dijkstra`], but keeps every cheapest path instead of just one. Every step has to cost
+/// more than zero, or a node might be finished before all of its predecessors are found.
+pub fn dijkstra_all<N, C, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut prev: HashMap<N, Vec<N>> = HashMap::new();
+    let mut heap = BinaryHeap::from([MinScored(C::default(), start.clone())]);
+
+    while let Some(MinScored(cost, node)) = heap.pop() {
+        if cost > costs[&node] {
+            continue;
+        }
+
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+            match costs.entry(next.clone()) {
+                Entry::Occupied(mut known) => match next_cost.cmp(known.get()) {
+                    Ordering::Less => {
+                        known.insert(next_cost);
+                        prev.insert(next.clone(), vec![node.clone()]);
+                        heap.push(MinScored(next_cost, next));
+                    }
+                    Ordering::Equal => prev.entry(next).or_default().push(node.clone()),
+                    Ordering::Greater => {}
+                },
+                Entry::Vacant(unknown) => {
+                    unknown.insert(next_cost);
+                    prev.insert(next.clone(), vec![node.clone()]);
+                    heap.push(MinScored(next_cost, next));
+                }
+            }
+        }
+    }
+
+    ShortestPaths { start, costs, prev }
+}
+
+/// Records any neighbor of `node` that's now cheaper to get to, and queues it up to be visited.
+fn relax<N, C>(
+    distances: &mut Distances<N, C>,
+    heap: &mut BinaryHeap<MinScored<C, N>>,
+    node: &N,
+    cost: C,
+    neighbors: impl IntoIterator<Item = (N, C)>,
+    mut heuristic: impl FnMut(&N) -> C,
+) where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C>,
+{
+    for (next, step_cost) in neighbors {
+        let next_cost = cost + step_cost;
+        if distances
+            .costs
+            .get(&next)
+            .is_some_and(|known| *known <= next_cost)
+        {
+            continue;
+        }
+
+        distances.costs.insert(next.clone(), next_cost);
+        distances.prev.insert(next.clone(), node.clone());
+        heap.push(MinScored(next_cost + heuristic(&next), next));
+    }
+}
+
+/// A heap entry that only compares by score, reversed so `BinaryHeap` pops the lowest first.
+struct MinScored<C, N>(C, N);
+
+impl<C: Ord, N> Ord for MinScored<C, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<C: Ord, N> PartialOrd for MinScored<C, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord, N> PartialEq for MinScored<C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: Ord, N> Eq for MinScored<C, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Point};
+
+    use super::*;
+
+    /// `#` is a wall and every other spot costs its digit to step onto.
+    fn example() -> Grid<Option<u64>> {
+        Grid::parse("1111\n1#91\n1191\n9#11", |c| {
+            Ok(c.to_digit(10).map(u64::from))
+        })
+        .unwrap()
+    }
+
+    fn weighted_neighbors(grid: &Grid<Option<u64>>, p: &Point) -> Vec<(Point, u64)> {
+        grid.neighbors(*p)
+            .filter_map(|(_, n)| grid[n].map(|cost| (n, cost)))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let grid = example();
+        let distances = bfs(Point { x: 0, y: 0 }, |p| {
+            weighted_neighbors(&grid, p).into_iter().map(|(n, _)| n)
+        });
+
+        assert_eq!(distances.cost(&Point { x: 3, y: 3 }), Some(6));
+        assert_eq!(distances.cost(&Point { x: 1, y: 1 }), None);
+        assert_eq!(
+            distances.path_to(&Point { x: 0, y: 2 }),
+            Some(vec![
+                Point { x: 0, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 0, y: 2 }
+            ])
+        );
+    }
+
+    #[test]
+    fn dijkstra_avoids_expensive_spots() {
+        let grid = example();
+        let distances = dijkstra(Point { x: 0, y: 0 }, |p| weighted_neighbors(&grid, p));
+        let end = Point { x: 3, y: 3 };
+
+        assert_eq!(distances.cost(&end), Some(6));
+        let path = distances.path_to(&end).unwrap();
+        assert!(!path.contains(&Point { x: 2, y: 1 }));
+        assert!(!path.contains(&Point { x: 2, y: 2 }));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = example();
+        let end = Point { x: 3, y: 3 };
+        let (cost, path) = astar(
+            Point { x: 0, y: 0 },
+            |p| weighted_neighbors(&grid, p),
+            |p| p.to_vec2().manhattan_distance(&end.to_vec2()),
+            |p| *p == end,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path.first(), Some(&Point { x: 0, y: 0 }));
+        assert_eq!(path.last(), Some(&end));
+        assert_eq!(
+            path.windows(2)
+                .map(|step| grid[step[1]].unwrap())
+                .sum::<u64>(),
+            cost
+        );
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_every_cheapest_path() {
+        let grid = Grid::new(3, 3, Some(1));
+        let end = Point { x: 2, y: 2 };
+        let paths = dijkstra_all(Point { x: 0, y: 0 }, |p| weighted_neighbors(&grid, p));
+
+        assert_eq!(paths.cost(&end), Some(4));
+        assert_eq!(paths.count_paths(&end), 6);
+        assert_eq!(paths.nodes_on_paths([&end]).len(), 9);
+        assert_eq!(
+            paths.nodes_on_paths([&Point { x: 1, y: 0 }]),
+            HashSet::from([Point { x: 0, y: 0 }, Point { x: 1, y: 0 }])
+        );
+
+        let all: HashSet<Vec<Point>> = paths.paths(&end).collect();
+        assert_eq!(all.len(), 6);
+        assert!(all.iter().all(|path| path.len() == 5
+            && path.first() == Some(&Point { x: 0, y: 0 })
+            && path.last() == Some(&end)));
+    }
+
+    #[test]
+    fn counting_paths_down_a_long_corridor_doesnt_overflow_the_stack() {
+        let end = 100_000;
+        let paths = dijkstra_all(0u32, |&n| (n < end).then_some((n + 1, 1u32)));
+
+        assert_eq!(paths.count_paths(&end), 1);
+    }
+
+    #[test]
+    fn dijkstra_all_only_keeps_the_cheapest_paths() {
+        let grid = example();
+        let end = Point { x: 3, y: 3 };
+        let paths = dijkstra_all(Point { x: 0, y: 0 }, |p| weighted_neighbors(&grid, p));
+
+        assert_eq!(paths.count_paths(&end), 1);
+        assert_eq!(paths.paths(&end).count(), 1);
+        assert_eq!(paths.count_paths(&Point { x: 1, y: 1 }), 0);
+        assert_eq!(paths.paths(&Point { x: 1, y: 1 }).next(), None);
+    }
+
+    #[test]
+    fn astar_gives_up_when_goal_is_unreachable() {
+        let grid = example();
+        let result = astar(
+            Point { x: 0, y: 0 },
+            |p| weighted_neighbors(&grid, p),
+            |_| 0,
+            |p| *p == Point { x: 1, y: 1 },
+        );
+        assert_eq!(result, None);
+    }
+}
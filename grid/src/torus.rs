@@ -0,0 +1,141 @@
+use strum::EnumIter;
+
+use crate::{Direction, Point, Vec2};
+
+/// A grid whose edges wrap around, so walking off one side comes back in on the other. It only
+/// knows its size, since what's on it tends to be a handful of things moving around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus {
+    width: usize,
+    height: usize,
+}
+
+/// One of the four quarters of a [`Torus`]. When a side is odd, the middle row or column isn't in
+/// any of them.
+#[derive(Debug, EnumIter, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Torus {
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "a torus can't be empty");
+        Self { width, height }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Wraps any position, however far off it is, back onto the torus.
+    pub fn wrap(&self, v: Vec2) -> Point {
+        Point {
+            x: v.x.rem_euclid(self.width as i64) as usize,
+            y: v.y.rem_euclid(self.height as i64) as usize,
+        }
+    }
+
+    pub fn neighbor(&self, p: Point, dir: Direction) -> Point {
+        self.wrap(p.add_direction(&dir))
+    }
+
+    /// Where something starting at `start` ends up after moving by `velocity` `steps` times. This
+    /// doesn't simulate every step, so it takes the same time for any number of steps.
+    pub fn advance(&self, start: Point, velocity: Vec2, steps: u64) -> Point {
+        let wrap_axis = |start: usize, velocity: i64, len: usize| {
+            let len = len as u128;
+            let velocity = velocity.rem_euclid(len as i64) as u128;
+            let moved = velocity * (steps as u128 % len) % len;
+            ((start as u128 % len + moved) % len) as usize
+        };
+
+        Point {
+            x: wrap_axis(start.x, velocity.x, self.width),
+            y: wrap_axis(start.y, velocity.y, self.height),
+        }
+    }
+
+    /// Which quadrant `p` is in, if it isn't on a middle row or column.
+    pub fn quadrant(&self, p: Point) -> Option<Quadrant> {
+        let left = p.x < self.width / 2;
+        let right = p.x > (self.width - 1) / 2;
+        let top = p.y < self.height / 2;
+        let bottom = p.y > (self.height - 1) / 2;
+
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Some(Quadrant::TopLeft),
+            (_, true, true, _) => Some(Quadrant::TopRight),
+            (true, _, _, true) => Some(Quadrant::BottomLeft),
+            (_, true, _, true) => Some(Quadrant::BottomRight),
+            _ => None,
+        }
+    }
+
+    /// How many of `points` are in each quadrant, in the order the quadrants are declared.
+    pub fn quadrant_counts(&self, points: impl IntoIterator<Item = Point>) -> [usize; 4] {
+        let mut counts = [0; 4];
+        points
+            .into_iter()
+            .filter_map(|p| self.quadrant(p))
+            .for_each(|quadrant| counts[quadrant as usize] += 1);
+        counts
+    }
+
+    /// Every point on the torus, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_wraps_like_stepping_one_at_a_time() {
+        let torus = Torus::new(11, 7);
+        let velocity = Vec2::new(2, -3);
+        let start = Point { x: 2, y: 4 };
+
+        let mut stepped = start;
+        for steps in 1..=100 {
+            stepped = torus.wrap(stepped.to_vec2() + velocity);
+            assert_eq!(torus.advance(start, velocity, steps), stepped);
+        }
+
+        assert_eq!(torus.advance(start, velocity, 5), Point { x: 1, y: 3 });
+    }
+
+    #[test]
+    fn advance_handles_huge_step_counts() {
+        let torus = Torus::new(101, 103);
+        let p = torus.advance(Point { x: 0, y: 0 }, Vec2::new(-99, 98), u64::MAX);
+        assert!(p.x < 101 && p.y < 103);
+    }
+
+    #[test]
+    fn middle_lines_are_in_no_quadrant() {
+        let odd = Torus::new(5, 3);
+        assert_eq!(odd.quadrant(Point { x: 2, y: 0 }), None);
+        assert_eq!(odd.quadrant(Point { x: 0, y: 1 }), None);
+        assert_eq!(
+            odd.quadrant(Point { x: 4, y: 2 }),
+            Some(Quadrant::BottomRight)
+        );
+
+        let even = Torus::new(4, 4);
+        assert_eq!(
+            even.quadrant(Point { x: 2, y: 1 }),
+            Some(Quadrant::TopRight)
+        );
+        assert_eq!(even.quadrant_counts(even.points()), [4; 4]);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{Direction, Direction8, Point};
+
+/// A signed position or offset. Unlike `Point`, this can go negative, so it's what to use for
+/// any math that might step off the edge of a grid.
+///
+/// The operators are plain `i64` math, so they panic on overflow in debug builds and wrap in
+/// release ones. Use the `checked_` versions when the numbers could get that big.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Distance from the origin moving only along x and y.
+    pub fn manhattan(&self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Distance from the origin when diagonal steps are allowed too.
+    pub fn chebyshev(&self) -> u64 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    pub fn checked_add(&self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, scale: i64) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_mul(scale)?,
+            self.y.checked_mul(scale)?,
+        ))
+    }
+
+    pub fn manhattan_distance(&self, other: &Vec2) -> u64 {
+        (*self - *other).manhattan()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Vec2) -> u64 {
+        (*self - *other).chebyshev()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, scale: i64) -> Vec2 {
+        Vec2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+/// Only fails for coordinates too big for an `i64`, which a point in a grid that fits in memory
+/// never has. [`Point::to_vec2`] is shorter for those.
+impl TryFrom<Point> for Vec2 {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(p: Point) -> Result<Self, Self::Error> {
+        Ok(Vec2::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(dir: Direction) -> Self {
+        let (x, y) = dir.offset();
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Direction8> for Vec2 {
+    fn from(dir: Direction8) -> Self {
+        let (x, y) = dir.offset();
+        Vec2::new(x, y)
+    }
+}
+
+/// Only works if both coordinates are non-negative. Use `Grid::checked_point` to also make sure
+/// it's inside a grid.
+impl TryFrom<Vec2> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(v: Vec2) -> Result<Self, Self::Error> {
+        Ok(Point {
+            x: v.x.try_into()?,
+            y: v.y.try_into()?,
+        })
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 5);
+        assert_eq!(a + b, Vec2::new(2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(-a, Vec2::new(-3, 2));
+    }
+
+    #[test]
+    fn distances_work() {
+        let a = Vec2::new(1, 1);
+        let b = Vec2::new(-2, 5);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+    }
+
+    #[test]
+    fn checked_arithmetic_catches_overflow() {
+        let big = Vec2::new(i64::MAX, 0);
+        assert_eq!(big.checked_add(Vec2::new(1, 0)), None);
+        assert_eq!(
+            big.checked_sub(Vec2::new(1, 0)),
+            Some(Vec2::new(i64::MAX - 1, 0))
+        );
+        assert_eq!(big.checked_mul(2), None);
+        assert_eq!(-big.checked_mul(-1).unwrap(), big);
+    }
+
+    #[test]
+    fn points_too_big_for_an_i64_are_not_vec2s() {
+        assert!(Vec2::try_from(Point {
+            x: usize::MAX,
+            y: 0
+        })
+        .is_err());
+        assert_eq!(Point { x: 2, y: 3 }.to_vec2(), Vec2::new(2, 3));
+    }
+
+    #[test]
+    fn negative_vec2_is_not_a_point() {
+        assert!(Point::try_from(Vec2::new(-1, 0)).is_err());
+        assert_eq!(Point::try_from(Vec2::new(2, 3)), Ok(Point { x: 2, y: 3 }));
+    }
+}
@@ -0,0 +1,181 @@
+//! Searching for something that has to face the way it's going, like a reindeer or a cart, where
+//! turning has a cost of its own.
+
+use std::collections::HashSet;
+
+use crate::{
+    search::{self, ShortestPaths},
+    Direction, Grid, Point, Turn,
+};
+
+/// Where a walker is and which way it's facing.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Pose {
+    pub pos: Point,
+    pub dir: Direction,
+}
+
+/// The rules for how a walker gets around. It either steps forward into the next open spot, or
+/// turns in place.
+#[derive(Debug, Clone)]
+pub struct Walker {
+    /// Cost of each step forward.
+    pub step_cost: u64,
+    /// Cost of each 90 degree turn, so turning around costs twice this.
+    pub turn_cost: u64,
+    /// Which turns can be made in place.
+    pub turns: Vec<Turn>,
+    /// Walk straight down corridors in one move, instead of stopping on every spot along the way.
+    /// Spots where the walker could turn off, dead ends and stops still get their own pose.
+    pub compress_corridors: bool,
+}
+
+impl Default for Walker {
+    fn default() -> Self {
+        Self {
+            step_cost: 1,
+            turn_cost: 1,
+            turns: vec![Turn::Left, Turn::Right],
+            compress_corridors: false,
+        }
+    }
+}
+
+impl Walker {
+    /// Every move out of `pose` and what it costs, where `open` says which spots can be walked on.
+    /// Corridors never get compressed past a spot in `stops`.
+    pub fn moves(&self, open: &Grid<bool>, pose: Pose, stops: &HashSet<Point>) -> Vec<(Pose, u64)> {
+        let mut moves: Vec<_> = self
+            .turns
+            .iter()
+            .filter(|turn| **turn != Turn::Straight)
+            .map(|turn| {
+                let turned = Pose {
+                    pos: pose.pos,
+                    dir: pose.dir.turn(*turn),
+                };
+                (turned, self.turn_cost * turn.quarter_turns())
+            })
+            .collect();
+
+        if let Some(pos) = self.walk_forward(open, pose, stops) {
+            let steps = pose.pos.to_vec2().manhattan_distance(&pos.to_vec2());
+            moves.push((Pose { pos, dir: pose.dir }, self.step_cost * steps));
+        }
+
+        moves
+    }
+
+    /// Every cheapest way from `start` to every pose the walker can get to.
+    pub fn shortest_paths(
+        &self,
+        open: &Grid<bool>,
+        start: Pose,
+        stops: &HashSet<Point>,
+    ) -> ShortestPaths<Pose, u64> {
+        search::dijkstra_all(start, |pose| self.moves(open, *pose, stops))
+    }
+
+    /// Where stepping forward from `pose` ends up, or `None` if it's blocked.
+    fn walk_forward(&self, open: &Grid<bool>, pose: Pose, stops: &HashSet<Point>) -> Option<Point> {
+        let is_open = |p: Option<Point>| p.is_some_and(|p| open[p]);
+
+        let mut pos = open.neighbor(pose.pos, pose.dir).filter(|p| open[*p])?;
+        while self.compress_corridors && !stops.contains(&pos) {
+            let ahead = open.neighbor(pos, pose.dir);
+            let can_turn_off = [pose.dir.turn_left(), pose.dir.turn_right()]
+                .iter()
+                .any(|side| is_open(open.neighbor(pos, *side)));
+
+            if can_turn_off || !is_open(ahead) {
+                break;
+            }
+            pos = ahead.expect("ahead should be open");
+        }
+
+        Some(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A map with one bend, so the cheapest way around has to turn once.
+    fn example() -> (Grid<bool>, Point, Point) {
+        let (grid, [start, end]) =
+            Grid::parse_with_markers("######\n#...E#\n#.####\n#S...#\n######", ['S', 'E'], |c| {
+                Ok(c != '#')
+            })
+            .unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn turns_and_steps_cost_what_they_are_set_to() {
+        let (open, start, end) = example();
+        let walker = Walker {
+            turn_cost: 1000,
+            ..Walker::default()
+        };
+        let paths = walker.shortest_paths(
+            &open,
+            Pose {
+                pos: start,
+                dir: Direction::Up,
+            },
+            &HashSet::new(),
+        );
+
+        let cost = paths.cost(&Pose {
+            pos: end,
+            dir: Direction::Right,
+        });
+        assert_eq!(cost, Some(1005));
+    }
+
+    #[test]
+    fn compressing_corridors_keeps_costs() {
+        let (open, start, end) = example();
+        let start = Pose {
+            pos: start,
+            dir: Direction::Up,
+        };
+        let end = Pose {
+            pos: end,
+            dir: Direction::Right,
+        };
+        let stops = HashSet::from([end.pos]);
+
+        let walker = Walker {
+            compress_corridors: true,
+            ..Walker::default()
+        };
+        let paths = walker.shortest_paths(&open, start, &stops);
+
+        assert_eq!(paths.cost(&end), Some(6));
+        let path = paths.paths(&end).next().unwrap();
+        assert_eq!(path.len(), 4, "{path:?}");
+    }
+
+    #[test]
+    fn turning_around_costs_two_quarter_turns() {
+        let (open, start, _) = example();
+        let start = Pose {
+            pos: start,
+            dir: Direction::Right,
+        };
+        let back = Pose {
+            pos: start.pos,
+            dir: Direction::Left,
+        };
+
+        let walker = Walker {
+            turns: vec![Turn::Back],
+            turn_cost: 10,
+            ..Walker::default()
+        };
+        let moves = walker.moves(&open, start, &HashSet::new());
+        assert!(moves.contains(&(back, 20)));
+    }
+}